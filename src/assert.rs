@@ -9,8 +9,8 @@
 //! #[tokio::test]
 //! async fn custom_response_struct() -> Result<(), grillon::Error> {
 //!     use async_trait::async_trait;
+//!     use bytes::Bytes;
 //!     use grillon::{header::HeaderMap, Assert, Response, StatusCode};
-//!     use serde_json::Value;
 //!
 //!     struct ResponseWrapper {
 //!         pub response: reqwest::Response,
@@ -22,8 +22,8 @@
 //!             self.response.status()
 //!         }
 //!
-//!         async fn json(self) -> Option<Value> {
-//!             self.response.json::<Value>().await.ok()
+//!         async fn bytes(self) -> Option<Bytes> {
+//!             self.response.bytes().await.ok()
 //!         }
 //!
 //!         fn headers(&self) -> HeaderMap {
@@ -42,15 +42,26 @@
 //!  }
 //! ```
 
-use crate::assertion::{Assertion, AssertionResult, Hand, UnprocessableReason};
+use crate::assertion::{with_request, Assertion, AssertionResult, Hand, UnprocessableReason};
+use crate::context::Context;
 use crate::dsl::http::*;
 use crate::dsl::json_path::{JsonPathDsl, JsonPathResult};
+use crate::dsl::Predicate;
 use crate::dsl::{Expression, Part};
+use crate::error::Error;
 use crate::grillon::LogSettings;
+use crate::report::{Report, ReportEntry, RequestInfo};
+use crate::response::{decode_json, decode_text, JsonDecodeError};
 use crate::Response;
+use bytes::Bytes;
 use http::HeaderValue;
-use http::{header::AsHeaderName, HeaderMap, StatusCode};
+use http::{
+    header::{AsHeaderName, SET_COOKIE},
+    HeaderMap, StatusCode,
+};
+use serde::Serialize;
 use serde_json::Value;
+use std::fmt::Debug;
 
 /// [`Assert`] uses an internal representation of the http response to assert
 /// against. If the HTTP request was successfully sent, then each field will be
@@ -61,12 +72,97 @@ pub struct Assert {
     pub headers: Option<HeaderMap>,
     /// The http response status to assert.
     pub status: Option<StatusCode>,
-    /// The http response json body to assert.
-    pub json: Option<Option<Value>>,
+    /// The http response json body to assert. Contains an error when the body
+    /// cannot be decoded as json.
+    pub json: Option<Result<Option<Value>, JsonDecodeError>>,
+    /// The http response text body to assert.
+    pub text: Option<String>,
+    /// The http response raw body to assert.
+    pub body: Option<Bytes>,
     /// The http response time (in milliseconds) to assert.
     pub response_time_ms: Option<u64>,
     /// The test results output.
     pub log_settings: LogSettings,
+    /// The context storing the captured values.
+    context: Context,
+    /// The request whose response is asserted.
+    request: Option<RequestInfo>,
+    /// The assertions evaluated so far.
+    records: Records,
+}
+
+/// The assertions evaluated by an [`Assert`], in evaluation order.
+///
+/// With [`LogSettings::SoftAssert`], the failures are reported with a single
+/// panic when the records are finished or dropped.
+struct Records {
+    entries: Vec<ReportEntry>,
+    soft: bool,
+    finished: bool,
+    /// The number of entries inherited from the cloned records. They are
+    /// already reported by the original records.
+    inherited: usize,
+}
+
+impl Records {
+    fn new(log_settings: &LogSettings) -> Self {
+        Self {
+            entries: Vec::new(),
+            soft: matches!(log_settings, LogSettings::SoftAssert),
+            finished: false,
+            inherited: 0,
+        }
+    }
+
+    /// Panics with the soft assertion failures, only once.
+    fn finish(&mut self) {
+        if self.finished || !self.soft {
+            return;
+        }
+        self.finished = true;
+
+        let failures: Vec<String> = self.entries[self.inherited..]
+            .iter()
+            .filter(|entry| {
+                matches!(
+                    entry.result,
+                    AssertionResult::Failed | AssertionResult::Unprocessable(_)
+                )
+            })
+            .map(|entry| format!("- {}", entry.summary()))
+            .collect();
+        if failures.is_empty() {
+            return;
+        }
+
+        panic!(
+            "\n\n{} soft assertion(s) failed:\n{}",
+            failures.len(),
+            failures.join("\n")
+        );
+    }
+}
+
+// A clone keeps the entries for its report, but only panics with the failures
+// evaluated after the clone, so a soft failure is reported once.
+impl Clone for Records {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            soft: self.soft,
+            finished: self.finished,
+            inherited: self.entries.len(),
+        }
+    }
+}
+
+impl Drop for Records {
+    fn drop(&mut self) {
+        // Avoid a double panic when the thread is already unwinding.
+        if !std::thread::panicking() {
+            self.finish();
+        }
+    }
 }
 
 impl Assert {
@@ -77,25 +173,216 @@ impl Assert {
         response_time_ms: Option<u64>,
         log_settings: LogSettings,
     ) -> Self {
-        if let Some(response) = response {
-            return Assert {
-                headers: Some(response.headers().clone()),
-                status: Some(response.status()),
-                json: Some(response.json().await),
-                response_time_ms,
-                log_settings,
-            };
+        let Some(response) = response else {
+            return Self::without_response(log_settings);
         };
 
+        let headers = response.headers();
+        let status = response.status();
+        // The body can only be consumed once, the json and text
+        // representations are derived from the raw bytes.
+        match response.bytes().await {
+            Some(body) => Self::from_parts(headers, status, body, response_time_ms, log_settings),
+            None => {
+                // A body that cannot be read must not be asserted as an empty
+                // body, only the status and the headers are kept.
+                let mut assert = Self::without_response(log_settings);
+                assert.headers = Some(headers);
+                assert.status = Some(status);
+                assert.response_time_ms = response_time_ms;
+                let assertion = Assertion {
+                    part: Part::NoPart,
+                    predicate: Predicate::NoPredicate,
+                    left: Hand::Empty::<Value>,
+                    right: Hand::Empty,
+                    result: AssertionResult::Unprocessable(
+                        UnprocessableReason::HttpRequestFailure(
+                            "the response body cannot be read".to_string(),
+                        ),
+                    ),
+                }
+                .assert_with_request(&assert.log_settings, assert.request.as_ref());
+                assert.record(&assertion);
+
+                assert
+            }
+        }
+    }
+
+    /// Creates an `Assert` instance from a response whose body has already
+    /// been read.
+    pub(crate) fn from_parts(
+        headers: HeaderMap,
+        status: StatusCode,
+        body: Bytes,
+        response_time_ms: Option<u64>,
+        log_settings: LogSettings,
+    ) -> Self {
+        Assert {
+            headers: Some(headers),
+            status: Some(status),
+            json: Some(decode_json(&body)),
+            text: Some(decode_text(&body)),
+            body: Some(body),
+            response_time_ms,
+            context: Context::default(),
+            request: None,
+            records: Records::new(&log_settings),
+            log_settings,
+        }
+    }
+
+    /// Creates an `Assert` instance without response, no assertion is run
+    /// against the parts of the response.
+    pub(crate) fn without_response(log_settings: LogSettings) -> Self {
         Assert {
             headers: None,
             status: None,
             json: None,
+            text: None,
+            body: None,
             response_time_ms: None,
+            context: Context::default(),
+            request: None,
+            records: Records::new(&log_settings),
             log_settings,
         }
     }
 
+    /// Sets the [`Context`] where the captured values are stored.
+    ///
+    /// An [`Assert`] created from a [`Grillon`] request shares the context of
+    /// the [`Grillon`] instance.
+    ///
+    /// [`Grillon`]: crate::Grillon
+    pub fn with_context(mut self, context: Context) -> Self {
+        self.context = context;
+
+        self
+    }
+
+    /// Sets the request whose response is asserted. It's attached to every
+    /// recorded assertion and json log.
+    ///
+    /// An [`Assert`] created from a [`Grillon`] request holds this request.
+    ///
+    /// [`Grillon`]: crate::Grillon
+    pub fn with_request(mut self, request: RequestInfo) -> Self {
+        self.request = Some(request);
+
+        self
+    }
+
+    /// Stores the value found at the given json path in the [`Context`] under
+    /// the given name.
+    ///
+    /// The json path must match exactly one value, which is stored as-is. A
+    /// path matching no value or several values makes an unprocessable
+    /// assertion, use [`Assert::capture_all`] to store every match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result, json, dsl::http::is_success};
+    /// # async fn run() -> Result<()> {
+    /// let grillon = Grillon::new("https://jsonplaceholder.typicode.com")?;
+    ///
+    /// grillon
+    ///     .post("posts")
+    ///     .payload(json!({ "title": "foo" }))
+    ///     .assert()
+    ///     .await
+    ///     .capture("post_id", "$.id");
+    ///
+    /// grillon
+    ///     .get("posts/{{post_id}}")
+    ///     .assert()
+    ///     .await
+    ///     .status(is_success());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn capture(mut self, name: &str, path: &str) -> Assert {
+        let Some(mut values) = self.query_capture(path) else {
+            return self;
+        };
+
+        let reason = match values.len() {
+            1 => {
+                self.context.set(name, values.remove(0));
+                return self;
+            }
+            0 => {
+                UnprocessableReason::CaptureFailure(format!("no value found at json path '{path}'"))
+            }
+            count => UnprocessableReason::CaptureFailure(format!(
+                "{count} values found at json path '{path}', use capture_all to capture them"
+            )),
+        };
+        self.unprocessable(Part::JsonPath, Predicate::NoPredicate, reason);
+
+        self
+    }
+
+    /// Stores the json array of all the values found at the given json path
+    /// in the [`Context`] under the given name, in document order. The array
+    /// is stored whatever the number of matches, even when it's empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # async fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .get("posts")
+    ///     .assert()
+    ///     .await
+    ///     .capture_all("post_ids", "$[*].id");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn capture_all(mut self, name: &str, path: &str) -> Assert {
+        if let Some(values) = self.query_capture(path) {
+            self.context.set(name, Value::Array(values));
+        }
+
+        self
+    }
+
+    /// Stores the value of the given header in the [`Context`] under the
+    /// given name, as a json string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result, header::LOCATION};
+    /// # async fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .post("posts")
+    ///     .assert()
+    ///     .await
+    ///     .capture_header("post_location", LOCATION);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn capture_header<H: AsHeaderName>(mut self, name: &str, header_name: H) -> Assert {
+        let Some(headers) = &self.headers else {
+            return self;
+        };
+
+        let reason = match headers.get(header_name).map(HeaderValue::to_str) {
+            Some(Ok(value)) => {
+                self.context.set(name, Value::String(value.to_string()));
+                return self;
+            }
+            Some(Err(err)) => UnprocessableReason::InvalidHeaderValue(err.to_string()),
+            None => UnprocessableReason::MissingHeader,
+        };
+        self.unprocessable(Part::Header, Predicate::NoPredicate, reason);
+
+        self
+    }
+
     /// Extends the built-in assertions with a custom assertion.
     /// The closure gives access to the [`Assert`] instance.
     ///
@@ -110,8 +397,8 @@ impl Assert {
     ///     .await
     ///     .status(is_between(200, 299))
     ///     .assert_fn(|assert| {
-    ///         assert!(!assert.headers.is_empty());
-    ///         assert!(assert.status == StatusCode::CREATED);
+    ///         assert!(assert.headers.is_some());
+    ///         assert!(assert.status == Some(StatusCode::CREATED));
     ///         assert!(assert.json.is_some());
     ///
     ///         println!("Json response : {:#?}", assert.json);
@@ -130,151 +417,441 @@ impl Assert {
         self
     }
 
+    /// Returns the summary of the assertions evaluated so far.
+    ///
+    /// With [`LogSettings::StdAssert`] the first failure panics, use
+    /// [`LogSettings::StdOutput`], [`LogSettings::JsonOutput`] or
+    /// [`LogSettings::SoftAssert`] to evaluate every assertion and inspect the
+    /// failures.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, LogSettings, Result, dsl::{http::is_success, is_less_than}};
+    /// # async fn run() -> Result<()> {
+    /// let report = Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .log_settings(LogSettings::StdOutput)
+    ///     .get("users")
+    ///     .assert()
+    ///     .await
+    ///     .status(is_success())
+    ///     .response_time(is_less_than(100))
+    ///     .report();
+    ///
+    /// for failure in report.failed {
+    ///     println!("{} failed", failure.part);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn report(&self) -> Report {
+        self.records.entries.iter().collect()
+    }
+
+    /// Consumes the [`Assert`] and returns its [`Report`], or an
+    /// [`Error::AssertionsFailed`] with every failed and unprocessable
+    /// assertion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, LogSettings, Result, dsl::http::is_success};
+    /// # async fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .log_settings(LogSettings::StdOutput)
+    ///     .get("users")
+    ///     .assert()
+    ///     .await
+    ///     .status(is_success())
+    ///     .into_result()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// With [`LogSettings::SoftAssert`], the failures are returned instead of
+    /// panicking.
+    pub fn into_result(mut self) -> crate::Result<Report> {
+        self.records.finished = true;
+
+        let report = self.report();
+        if report.is_success() {
+            return Ok(report);
+        }
+
+        Err(Error::AssertionsFailed(
+            report.failures().cloned().collect(),
+        ))
+    }
+
+    /// Ends the chain of assertions.
+    ///
+    /// With [`LogSettings::SoftAssert`], panics once with the list of every
+    /// failed and unprocessable assertion. Dropping the [`Assert`] has the
+    /// same effect, `finish` makes the end of the chain explicit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, LogSettings, Result, json, dsl::{http::is_success, is}};
+    /// # async fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .log_settings(LogSettings::SoftAssert)
+    ///     .get("users/1")
+    ///     .assert()
+    ///     .await
+    ///     .status(is_success())
+    ///     .json_path("$.id", is(json!(1)))
+    ///     .finish();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn finish(mut self) {
+        self.records.finish();
+    }
+
     /// Asserts the status of the response.
-    pub fn status<T>(self, expr: Expression<T>) -> Assert
+    pub fn status<T>(mut self, expr: Expression<T>) -> Assert
     where
         T: StatusCodeDsl<StatusCode>,
     {
         if let Some(status) = self.status {
-            let _assertion = expr.value.eval(status, expr.predicate, &self.log_settings);
+            let assertion =
+                self.eval(|log_settings| expr.value.eval(status, expr.predicate, log_settings));
+            self.record(&assertion);
         }
 
         self
     }
 
     /// Asserts the json body of the response.
-    pub fn json_body<T>(self, expr: Expression<T>) -> Assert
+    pub fn json_body<T>(mut self, expr: Expression<T>) -> Assert
     where
         T: JsonBodyDsl<Value>,
     {
-        if let Some(json) = &self.json {
-            let actual = if let Some(body) = json.clone() {
-                body
-            } else {
-                let assertion = Assertion {
-                    part: Part::JsonPath,
-                    predicate: expr.predicate,
-                    left: Hand::Empty::<Value>,
-                    right: Hand::Empty,
-                    result: AssertionResult::Unprocessable(UnprocessableReason::MissingJsonBody),
-                };
-                assertion.assert(&self.log_settings);
-
-                return self;
-            };
-            let _assertion = expr.value.eval(actual, expr.predicate, &self.log_settings);
+        if let Some(actual) = self.decoded_json(Part::JsonBody, &expr.predicate) {
+            let assertion =
+                self.eval(|log_settings| expr.value.eval(actual, expr.predicate, log_settings));
+            self.record(&assertion);
         }
 
         self
     }
 
     /// Asserts the value found at the given json path.
-    pub fn json_path<T>(self, path: &str, expr: Expression<T>) -> Assert
+    pub fn json_path<T>(mut self, path: &str, expr: Expression<T>) -> Assert
     where
         T: JsonPathDsl<Value>,
     {
         use jsonpath_rust::JsonPathQuery;
 
-        if let Some(json) = &self.json {
-            // Check for empty json body
-            let json_body = if let Some(body) = json.clone() {
-                body
-            } else {
+        let Some(json_body) = self.decoded_json(Part::JsonPath, &expr.predicate) else {
+            return self;
+        };
+
+        // Check for unprocessable json path
+        let jsonpath_value = match json_body.path(path) {
+            Ok(json) => json,
+            Err(_) => {
                 let assertion = Assertion {
                     part: Part::JsonPath,
                     predicate: expr.predicate,
                     left: Hand::Empty::<Value>,
                     right: Hand::Empty,
-                    result: AssertionResult::Unprocessable(UnprocessableReason::MissingJsonBody),
+                    result: AssertionResult::Unprocessable(UnprocessableReason::InvalidJsonPath(
+                        path.to_string(),
+                    )),
                 };
-                assertion.assert(&self.log_settings);
+                let assertion =
+                    assertion.assert_with_request(&self.log_settings, self.request.as_ref());
+                self.record(&assertion);
 
                 return self;
-            };
-
-            // Check for unprocessable json path
-            let jsonpath_value = match json_body.path(path) {
-                Ok(json) => json,
-                Err(_) => {
-                    let assertion = Assertion {
-                        part: Part::JsonPath,
-                        predicate: expr.predicate,
-                        left: Hand::Empty::<Value>,
-                        right: Hand::Empty,
-                        result: AssertionResult::Unprocessable(
-                            UnprocessableReason::InvalidJsonPath(path.to_string()),
-                        ),
-                    };
-                    assertion.assert(&self.log_settings);
+            }
+        };
 
-                    return self;
-                }
-            };
+        let jsonpath_res = JsonPathResult::new(path, jsonpath_value);
+
+        let assertion =
+            self.eval(|log_settings| expr.value.eval(jsonpath_res, expr.predicate, log_settings));
+        self.record(&assertion);
+
+        self
+    }
+
+    /// Asserts the body of the response as text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result, dsl::{contains, matches}};
+    /// # async fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .get("")
+    ///     .assert()
+    ///     .await
+    ///     .text_body(contains("<title>JSONPlaceholder"))
+    ///     .text_body(matches(r#"<html lang="\w+">"#));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn text_body<T>(mut self, expr: Expression<T>) -> Assert
+    where
+        T: TextBodyDsl<String>,
+    {
+        if let Some(text) = &self.text {
+            let assertion = self
+                .eval(|log_settings| expr.value.eval(text.clone(), expr.predicate, log_settings));
+            self.record(&assertion);
+        }
 
-            let jsonpath_res = JsonPathResult::new(path, jsonpath_value);
+        self
+    }
 
-            let _assertion = expr
-                .value
-                .eval(jsonpath_res, expr.predicate, &self.log_settings);
+    /// Asserts the raw body of the response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result, dsl::{contains, is_not}};
+    /// # async fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .get("favicon.ico")
+    ///     .assert()
+    ///     .await
+    ///     .body_bytes(is_not(b""))
+    ///     .body_bytes(contains(&[0x00, 0x00, 0x01, 0x00][..]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body_bytes<T>(mut self, expr: Expression<T>) -> Assert
+    where
+        T: BodyBytesDsl<Bytes>,
+    {
+        if let Some(body) = &self.body {
+            let assertion = self
+                .eval(|log_settings| expr.value.eval(body.clone(), expr.predicate, log_settings));
+            self.record(&assertion);
         }
 
         self
     }
 
     /// Asserts the response time (in milliseconds).
-    pub fn response_time<T>(self, expr: Expression<T>) -> Assert
+    pub fn response_time<T>(mut self, expr: Expression<T>) -> Assert
     where
         T: TimeDsl<u64>,
     {
         if let Some(response_time_ms) = self.response_time_ms {
-            let _assertion = expr
-                .value
-                .eval(response_time_ms, expr.predicate, &self.log_settings);
+            let assertion = self.eval(|log_settings| {
+                expr.value
+                    .eval(response_time_ms, expr.predicate, log_settings)
+            });
+            self.record(&assertion);
         }
 
         self
     }
 
     /// Asserts the headers of the response.
-    pub fn headers<T>(self, expr: Expression<T>) -> Assert
+    pub fn headers<T>(mut self, expr: Expression<T>) -> Assert
     where
         T: HeadersDsl<HeaderMap>,
     {
         if let Some(headers) = &self.headers {
-            let _assertion = expr
-                .value
-                .eval(headers.clone(), expr.predicate, &self.log_settings);
+            let assertion = self.eval(|log_settings| {
+                expr.value
+                    .eval(headers.clone(), expr.predicate, log_settings)
+            });
+            self.record(&assertion);
+        }
+
+        self
+    }
+
+    /// Asserts the headers of the response match a snapshot file, see
+    /// [`matches_snapshot`]. The failure log shows the json diff from the
+    /// snapshot to the headers.
+    ///
+    /// [`matches_snapshot`]: crate::dsl::matches_snapshot
+    pub fn headers_snapshot<T>(mut self, expr: Expression<T>) -> Assert
+    where
+        T: HeadersSnapshotDsl<HeaderMap>,
+    {
+        if let Some(headers) = &self.headers {
+            let assertion = self.eval(|log_settings| {
+                expr.value
+                    .eval(headers.clone(), expr.predicate, log_settings)
+            });
+            self.record(&assertion);
         }
 
         self
     }
 
     /// Asserts a specific header of the response.
-    pub fn header<H, T>(self, header_name: H, expr: Expression<T>) -> Assert
+    pub fn header<H, T>(mut self, header_name: H, expr: Expression<T>) -> Assert
     where
         H: AsHeaderName,
         T: HeaderDsl<HeaderValue>,
     {
-        if let Some(headers) = self.headers.clone() {
-            if let Some(actual_header_val) = headers.get(header_name) {
-                let _assertion = expr.value.eval(
-                    actual_header_val.clone(),
-                    expr.predicate,
-                    &self.log_settings,
+        if let Some(headers) = &self.headers {
+            let assertion = self.eval(|log_settings| match headers.get(header_name) {
+                Some(actual_header_val) => {
+                    expr.value
+                        .eval(actual_header_val.clone(), expr.predicate, log_settings)
+                }
+                None => expr.value.eval_missing(expr.predicate, log_settings),
+            });
+            self.record(&assertion);
+        }
+
+        self
+    }
+
+    /// Asserts all the values of a header of the response, in the order they
+    /// were received. A missing header has no value.
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result, dsl::{contains, is}, header::VARY};
+    /// # async fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .get("users/1")
+    ///     .assert()
+    ///     .await
+    ///     .header_values(VARY, is(vec!["Origin", "Accept-Encoding"]))
+    ///     .header_values("set-cookie", contains("theme=dark"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn header_values<H, T>(mut self, header_name: H, expr: Expression<T>) -> Assert
+    where
+        H: AsHeaderName,
+        T: HeaderValuesDsl<Vec<HeaderValue>>,
+    {
+        if let Some(headers) = &self.headers {
+            let actual = headers.get_all(header_name).iter().cloned().collect();
+            let assertion =
+                self.eval(|log_settings| expr.value.eval(actual, expr.predicate, log_settings));
+            self.record(&assertion);
+        }
+
+        self
+    }
+
+    /// Asserts a cookie set by the `Set-Cookie` headers of the response. When
+    /// several headers set the cookie, the last one is asserted.
+    ///
+    /// `is` and `is_not` compare the value of the cookie, `includes` compares
+    /// the expected [`cookie_attributes`] regardless of their order in the
+    /// header.
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result, dsl::{includes, is, is_absent, http::cookie_attributes}};
+    /// # async fn run() -> Result<()> {
+    /// Grillon::new("https://example.com")?
+    ///     .post("login")
+    ///     .assert()
+    ///     .await
+    ///     .cookie("session", is("abc123"))
+    ///     .cookie("session", includes(cookie_attributes().http_only(true).path("/")))
+    ///     .cookie("tracking", is_absent());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cookie<T>(mut self, name: &str, expr: Expression<T>) -> Assert
+    where
+        T: CookieDsl<Cookie>,
+    {
+        if let Some(headers) = &self.headers {
+            let actual = headers
+                .get_all(SET_COOKIE)
+                .iter()
+                .rev()
+                .filter_map(|value| value.to_str().ok())
+                .filter_map(Cookie::parse)
+                .find(|cookie| cookie.name.as_deref() == Some(name));
+            let assertion = self.eval(|log_settings| match actual {
+                Some(cookie) => expr.value.eval(cookie, expr.predicate, log_settings),
+                None => expr.value.eval_missing(name, expr.predicate, log_settings),
+            });
+            self.record(&assertion);
+        }
+
+        self
+    }
+
+    /// Returns the values found at the given json path, for a capture.
+    ///
+    /// When the json body cannot be decoded or the json path is invalid, an
+    /// unprocessable assertion is produced and `None` is returned.
+    fn query_capture(&mut self, path: &str) -> Option<Vec<Value>> {
+        use jsonpath_rust::JsonPathQuery;
+
+        let json_body = self.decoded_json(Part::JsonPath, &Predicate::NoPredicate)?;
+
+        match json_body.path(path) {
+            Ok(Value::Array(values)) => Some(values),
+            // No match can result in null.
+            Ok(Value::Null) => Some(vec![]),
+            Ok(value) => Some(vec![value]),
+            Err(_) => {
+                self.unprocessable(
+                    Part::JsonPath,
+                    Predicate::NoPredicate,
+                    UnprocessableReason::InvalidJsonPath(path.to_string()),
                 );
-            } else {
-                // Handle missing header name
-                let assertion = Assertion {
-                    part: Part::Header,
-                    predicate: expr.predicate,
-                    left: Hand::Empty::<&str>,
-                    right: Hand::Empty,
-                    result: AssertionResult::Unprocessable(UnprocessableReason::MissingHeader),
-                };
-                assertion.assert(&self.log_settings);
+
+                None
             }
         }
+    }
 
-        self
+    /// Returns the decoded json body of the response.
+    ///
+    /// When the body is missing or cannot be decoded, an unprocessable
+    /// assertion is produced for the given [`Part`] and `None` is returned.
+    fn decoded_json(&mut self, part: Part, predicate: &Predicate) -> Option<Value> {
+        let reason = match self.json.as_ref()? {
+            Ok(Some(body)) => return Some(body.clone()),
+            Ok(None) => UnprocessableReason::MissingJsonBody,
+            Err(err) => UnprocessableReason::InvalidJsonBody(err.to_string()),
+        };
+
+        self.unprocessable(part, predicate.clone(), reason);
+
+        None
+    }
+
+    /// Produces an unprocessable assertion for the given [`Part`].
+    fn unprocessable(&mut self, part: Part, predicate: Predicate, reason: UnprocessableReason) {
+        let assertion = Assertion {
+            part,
+            predicate,
+            left: Hand::Empty::<Value>,
+            right: Hand::Empty,
+            result: AssertionResult::Unprocessable(reason),
+        };
+        let assertion = assertion.assert_with_request(&self.log_settings, self.request.as_ref());
+        self.record(&assertion);
+    }
+
+    /// Evaluates an assertion of the DSL, its json log holding the request of
+    /// the response.
+    fn eval<T>(&self, eval: impl FnOnce(&LogSettings) -> Assertion<T>) -> Assertion<T>
+    where
+        T: Debug + Serialize,
+    {
+        with_request(self.request.as_ref(), || eval(&self.log_settings))
+    }
+
+    /// Records an evaluated assertion for the [`Report`].
+    pub(crate) fn record<T>(&mut self, assertion: &Assertion<T>)
+    where
+        T: Debug + Serialize + 'static,
+    {
+        let mut entry = ReportEntry::from(assertion);
+        entry.request = self.request.clone();
+
+        self.records.entries.push(entry);
     }
 }
@@ -0,0 +1,286 @@
+//! Structural diff between two json values.
+//!
+//! The differences are located with [json pointers] and go from the expected
+//! value (old) to the actual value (new).
+//!
+//! [json pointers]: https://datatracker.ietf.org/doc/html/rfc6901
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// A value found in the actual json value but not in the expected one.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Added {
+    /// The json pointer of the value.
+    pub pointer: String,
+    /// The actual value.
+    pub new: Value,
+}
+
+/// A value expected but not found in the actual json value.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Removed {
+    /// The json pointer of the value.
+    pub pointer: String,
+    /// The expected value.
+    pub old: Value,
+}
+
+/// A value found in both json values but with different contents.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Changed {
+    /// The json pointer of the value.
+    pub pointer: String,
+    /// The expected value.
+    pub old: Value,
+    /// The actual value.
+    pub new: Value,
+}
+
+/// The differences between an expected and an actual json value.
+///
+/// Objects are compared key by key and arrays index by index. Other values,
+/// and values of different types, are changed when they are not equal.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonDiff {
+    /// The values only found in the actual json value.
+    pub added: Vec<Added>,
+    /// The values only found in the expected json value.
+    pub removed: Vec<Removed>,
+    /// The values found in both json values but with different contents.
+    pub changed: Vec<Changed>,
+}
+
+impl JsonDiff {
+    /// Computes the differences going from the expected value to the actual
+    /// one.
+    pub fn new(expected: &Value, actual: &Value) -> Self {
+        let mut diff = JsonDiff::default();
+        diff.collect(expected, actual, String::new(), false);
+
+        diff
+    }
+
+    /// Computes the differences going from the expected value to the actual
+    /// one, the arrays being compared as multisets.
+    ///
+    /// The expected items without an equal actual item are removed, and the
+    /// actual items without an equal expected item are added. The pointer of
+    /// a removed item holds its index in the expected array, the pointer of
+    /// an added item its index in the actual array.
+    pub fn unordered(expected: &Value, actual: &Value) -> Self {
+        let mut diff = JsonDiff::default();
+        diff.collect(expected, actual, String::new(), true);
+
+        diff
+    }
+
+    /// Returns `true` if both json values are equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn collect(&mut self, expected: &Value, actual: &Value, pointer: String, unordered: bool) {
+        match (expected, actual) {
+            (Value::Object(expected), Value::Object(actual)) => {
+                for (key, old) in expected {
+                    let pointer = format!("{pointer}/{}", escape(key));
+                    match actual.get(key) {
+                        Some(new) => self.collect(old, new, pointer, unordered),
+                        None => self.removed.push(Removed {
+                            pointer,
+                            old: old.clone(),
+                        }),
+                    }
+                }
+                for (key, new) in actual {
+                    if !expected.contains_key(key) {
+                        self.added.push(Added {
+                            pointer: format!("{pointer}/{}", escape(key)),
+                            new: new.clone(),
+                        });
+                    }
+                }
+            }
+            // Equal items are interchangeable, pairing each expected item
+            // with the first free equal actual item is enough.
+            (Value::Array(expected), Value::Array(actual)) if unordered => {
+                let mut paired = vec![false; actual.len()];
+                for (index, old) in expected.iter().enumerate() {
+                    let pair = (0..actual.len())
+                        .find(|&i| !paired[i] && JsonDiff::unordered(old, &actual[i]).is_empty());
+                    match pair {
+                        Some(i) => paired[i] = true,
+                        None => self.removed.push(Removed {
+                            pointer: format!("{pointer}/{index}"),
+                            old: old.clone(),
+                        }),
+                    }
+                }
+                for (index, new) in actual.iter().enumerate() {
+                    if !paired[index] {
+                        self.added.push(Added {
+                            pointer: format!("{pointer}/{index}"),
+                            new: new.clone(),
+                        });
+                    }
+                }
+            }
+            (Value::Array(expected), Value::Array(actual)) => {
+                for (index, old) in expected.iter().enumerate() {
+                    let pointer = format!("{pointer}/{index}");
+                    match actual.get(index) {
+                        Some(new) => self.collect(old, new, pointer, unordered),
+                        None => self.removed.push(Removed {
+                            pointer,
+                            old: old.clone(),
+                        }),
+                    }
+                }
+                for (index, new) in actual.iter().enumerate().skip(expected.len()) {
+                    self.added.push(Added {
+                        pointer: format!("{pointer}/{index}"),
+                        new: new.clone(),
+                    });
+                }
+            }
+            (old, new) if old == new => {}
+            (old, new) => self.changed.push(Changed {
+                pointer,
+                old: old.clone(),
+                new: new.clone(),
+            }),
+        }
+    }
+
+    /// Renders the differences one per line, prefixed by `+` when added, `-`
+    /// when removed and `~` when changed. ANSI colors are used when `colored`
+    /// is `true`.
+    pub fn render(&self, colored: bool) -> String {
+        let paint = |color: &str, line: String| match colored {
+            true => format!("{color}{line}{RESET}"),
+            false => line,
+        };
+
+        let added = self
+            .added
+            .iter()
+            .map(|added| paint(GREEN, format!("+ {}: {}", added.pointer, added.new)));
+        let removed = self
+            .removed
+            .iter()
+            .map(|removed| paint(RED, format!("- {}: {}", removed.pointer, removed.old)));
+        let changed = self.changed.iter().map(|changed| {
+            paint(
+                YELLOW,
+                format!("~ {}: {} -> {}", changed.pointer, changed.old, changed.new),
+            )
+        });
+
+        changed
+            .chain(removed)
+            .chain(added)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for JsonDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(false))
+    }
+}
+
+/// Escapes a key to be used as a json pointer token.
+pub(crate) fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Added, Changed, JsonDiff, Removed};
+    use serde_json::json;
+
+    #[test]
+    fn it_should_diff_nested_values() {
+        let expected = json!({ "id": 1, "user": { "name": "Isaac", "age": 22 }, "tags": ["a"] });
+        let actual =
+            json!({ "id": 2, "user": { "name": "Isaac" }, "tags": ["a", "b"], "new": true });
+
+        assert_eq!(
+            JsonDiff::new(&expected, &actual),
+            JsonDiff {
+                added: vec![
+                    Added {
+                        pointer: "/tags/1".to_string(),
+                        new: json!("b")
+                    },
+                    Added {
+                        pointer: "/new".to_string(),
+                        new: json!(true)
+                    },
+                ],
+                removed: vec![Removed {
+                    pointer: "/user/age".to_string(),
+                    old: json!(22)
+                }],
+                changed: vec![Changed {
+                    pointer: "/id".to_string(),
+                    old: json!(1),
+                    new: json!(2)
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_diff_arrays_as_multisets() {
+        let expected =
+            json!({ "tags": ["a", "b", "b"], "items": [{ "id": 1, "tags": ["x", "y"] }] });
+        let actual = json!({ "tags": ["b", "c", "a"], "items": [{ "tags": ["y", "x"], "id": 1 }] });
+
+        assert_eq!(
+            JsonDiff::unordered(&expected, &actual),
+            JsonDiff {
+                added: vec![Added {
+                    pointer: "/tags/1".to_string(),
+                    new: json!("c")
+                }],
+                removed: vec![Removed {
+                    pointer: "/tags/2".to_string(),
+                    old: json!("b")
+                }],
+                changed: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_be_empty_for_equal_values() {
+        let value = json!({ "id": 1, "tags": ["a"] });
+
+        assert!(JsonDiff::new(&value, &value).is_empty());
+    }
+
+    #[test]
+    fn it_should_render_the_differences() {
+        let diff = JsonDiff::new(
+            &json!({ "id": 1, "name": "Isaac" }),
+            &json!({ "id": "1", "a/b": null }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            "~ /id: 1 -> \"1\"\n- /name: \"Isaac\"\n+ /a~1b: null"
+        );
+        assert_eq!(
+            diff.render(true),
+            "\x1b[33m~ /id: 1 -> \"1\"\x1b[0m\n\x1b[31m- /name: \"Isaac\"\x1b[0m\n\x1b[32m+ /a~1b: null\x1b[0m"
+        );
+    }
+}
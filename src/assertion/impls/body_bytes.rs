@@ -0,0 +1,158 @@
+use crate::{
+    assertion::{
+        traits::{Container, Equality, Matching},
+        Assertion, Hand,
+    },
+    dsl::{Part, Predicate},
+};
+use bytes::Bytes;
+use regex::bytes::Regex;
+
+use super::compile_regex;
+
+/// Returns `true` if `needle` is a contiguous sequence of `haystack`. An empty
+/// needle is always contained.
+fn contains_sequence(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.is_empty()
+        || haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
+
+impl Equality<[u8]> for Bytes {
+    type Assertion = Assertion<Bytes>;
+
+    fn is_eq(&self, rhs: &[u8]) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::Is,
+            part: Part::BodyBytes,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(Bytes::copy_from_slice(rhs)),
+            result: (self == rhs).into(),
+        }
+    }
+
+    fn is_ne(&self, rhs: &[u8]) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::IsNot,
+            part: Part::BodyBytes,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(Bytes::copy_from_slice(rhs)),
+            result: (self != rhs).into(),
+        }
+    }
+}
+
+impl Container<[u8]> for Bytes {
+    type Assertion = Assertion<Bytes>;
+
+    fn has(&self, rhs: &[u8]) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::Contains,
+            part: Part::BodyBytes,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(Bytes::copy_from_slice(rhs)),
+            result: contains_sequence(self, rhs).into(),
+        }
+    }
+
+    fn has_not(&self, rhs: &[u8]) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::DoesNotContain,
+            part: Part::BodyBytes,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(Bytes::copy_from_slice(rhs)),
+            result: (!contains_sequence(self, rhs)).into(),
+        }
+    }
+}
+
+impl Matching<str> for Bytes {
+    type Assertion = Assertion<Bytes>;
+
+    fn is_match(&self, re: &str) -> Self::Assertion {
+        let regex: Regex = match compile_regex(re, Predicate::Matches, Part::BodyBytes) {
+            Ok(regex) => regex,
+            Err(assertion) => return assertion,
+        };
+
+        Assertion {
+            predicate: Predicate::Matches,
+            part: Part::BodyBytes,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(Bytes::copy_from_slice(re.as_bytes())),
+            result: regex.is_match(self).into(),
+        }
+    }
+
+    fn is_not_match(&self, re: &str) -> Self::Assertion {
+        let regex: Regex = match compile_regex(re, Predicate::DoesNotMatch, Part::BodyBytes) {
+            Ok(regex) => regex,
+            Err(assertion) => return assertion,
+        };
+
+        Assertion {
+            predicate: Predicate::DoesNotMatch,
+            part: Part::BodyBytes,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(Bytes::copy_from_slice(re.as_bytes())),
+            result: (!regex.is_match(self)).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertion::traits::{Container, Equality, Matching};
+    use bytes::Bytes;
+
+    fn bytes_stub() -> Bytes {
+        Bytes::from_static(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR")
+    }
+
+    #[test]
+    fn impl_is_eq_bytes() {
+        let assertion = bytes_stub().is_eq(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR");
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_is_ne_bytes() {
+        let assertion = bytes_stub().is_ne(b"GIF89a");
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_has_bytes() {
+        let assertion = bytes_stub().has(b"IHDR");
+        assert!(assertion.passed(), "{}", assertion.log());
+
+        let assertion = bytes_stub().has(b"");
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_has_not_bytes() {
+        let assertion = bytes_stub().has_not(b"IEND");
+        assert!(assertion.passed(), "{}", assertion.log());
+
+        let assertion = bytes_stub().has_not(b"PNG");
+        assert!(assertion.failed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_matches() {
+        let assertion = bytes_stub().is_match(r"(?-u)^\x89PNG");
+        assert!(assertion.passed(), "{}", assertion.log());
+
+        let assertion = bytes_stub().is_not_match(r"^GIF8[79]a");
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_matches_invalid_regex() {
+        let assertion = bytes_stub().is_match(r"\");
+        assert!(assertion.failed(), "{}", assertion.log());
+        assert_eq!(assertion.log(), r"Invalid regex pattern: \");
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    assertion::{
+        traits::{Equality, Existence, Inclusion},
+        Assertion, Hand,
+    },
+    dsl::{http::Cookie, Part, Predicate},
+};
+
+/// Returns an expected cookie with only a value.
+fn with_value(value: &str) -> Cookie {
+    Cookie {
+        value: Some(value.to_string()),
+        ..Default::default()
+    }
+}
+
+impl Equality<str> for Cookie {
+    type Assertion = Assertion<Cookie>;
+
+    /// Compares the value of the cookie, the attributes are ignored.
+    fn is_eq(&self, rhs: &str) -> Self::Assertion {
+        let result = self.value.as_deref() == Some(rhs);
+
+        Assertion {
+            predicate: Predicate::Is,
+            part: Part::Cookie,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(with_value(rhs)),
+            result: result.into(),
+        }
+    }
+
+    fn is_ne(&self, rhs: &str) -> Self::Assertion {
+        let result = self.value.as_deref() != Some(rhs);
+
+        Assertion {
+            predicate: Predicate::IsNot,
+            part: Part::Cookie,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(with_value(rhs)),
+            result: result.into(),
+        }
+    }
+}
+
+impl Inclusion<Cookie> for Cookie {
+    type Assertion = Assertion<Cookie>;
+
+    /// Asserts the attributes set in `subset` are equal to the ones of the
+    /// cookie. The domains are compared case-insensitively, without their
+    /// leading dot.
+    fn includes(&self, subset: &Cookie) -> Self::Assertion {
+        fn check<T: PartialEq>(actual: &Option<T>, expected: &Option<T>) -> bool {
+            expected.is_none() || actual == expected
+        }
+
+        let domain = match (&self.domain, &subset.domain) {
+            (_, None) => true,
+            (Some(actual), Some(expected)) => {
+                actual.eq_ignore_ascii_case(expected.trim_start_matches('.'))
+            }
+            (None, Some(_)) => false,
+        };
+        let result = domain
+            && check(&self.name, &subset.name)
+            && check(&self.value, &subset.value)
+            && check(&self.http_only, &subset.http_only)
+            && check(&self.secure, &subset.secure)
+            && check(&self.same_site, &subset.same_site)
+            && check(&self.path, &subset.path)
+            && check(&self.max_age, &subset.max_age)
+            && check(&self.expires, &subset.expires);
+
+        Assertion {
+            predicate: Predicate::Includes,
+            part: Part::Cookie,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(subset.clone()),
+            result: result.into(),
+        }
+    }
+}
+
+impl Existence for Option<Cookie> {
+    type Assertion = Assertion<Cookie>;
+
+    fn is_present(&self) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::Exists,
+            part: Part::Cookie,
+            left: cookie_hand(self),
+            right: Hand::Empty,
+            result: self.is_some().into(),
+        }
+    }
+
+    fn is_absent(&self) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::DoesNotExist,
+            part: Part::Cookie,
+            left: cookie_hand(self),
+            right: Hand::Empty,
+            result: self.is_none().into(),
+        }
+    }
+}
+
+/// Returns the left hand of an existence test, empty when the cookie is
+/// missing.
+fn cookie_hand(cookie: &Option<Cookie>) -> Hand<Cookie> {
+    match cookie {
+        Some(cookie) => Hand::Left(cookie.clone()),
+        None => Hand::Empty,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertion::traits::{Equality, Existence, Inclusion};
+    use crate::dsl::http::{cookie_attributes, Cookie, SameSite};
+
+    const SESSION: &str = "session=abc123; Path=/; Domain=.Example.com; Max-Age=3600; \
+        Expires=Wed, 21 Oct 2026 07:28:00 GMT; Secure; HttpOnly; SameSite=Lax";
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn it_should_parse_all_attributes() {
+            let cookie = Cookie::parse(SESSION).unwrap();
+
+            assert_eq!(
+                cookie,
+                Cookie {
+                    name: Some("session".to_string()),
+                    value: Some("abc123".to_string()),
+                    http_only: Some(true),
+                    secure: Some(true),
+                    same_site: Some(SameSite::Lax),
+                    path: Some("/".to_string()),
+                    domain: Some("example.com".to_string()),
+                    max_age: Some(3600),
+                    expires: Some("Wed, 21 Oct 2026 07:28:00 GMT".to_string()),
+                }
+            );
+        }
+
+        #[test]
+        fn it_should_not_depend_on_attribute_order_and_case() {
+            let reordered = "session=abc123; samesite=lax; HTTPONLY; secure; \
+                expires=Wed, 21 Oct 2026 07:28:00 GMT; max-age=3600; domain=example.com; path=/";
+
+            assert_eq!(Cookie::parse(reordered), Cookie::parse(SESSION));
+        }
+
+        #[test]
+        fn it_should_parse_a_bare_cookie() {
+            let cookie = Cookie::parse("theme=\"dark\"").unwrap();
+
+            assert_eq!(cookie.value.as_deref(), Some("dark"));
+            assert_eq!(cookie.http_only, Some(false));
+            assert_eq!(cookie.secure, Some(false));
+            assert_eq!(cookie.same_site, None);
+            assert_eq!(cookie.max_age, None);
+        }
+
+        #[test]
+        fn it_should_ignore_invalid_attributes() {
+            let cookie = Cookie::parse("theme=dark; Max-Age=soon; SameSite=Sometimes").unwrap();
+
+            assert_eq!(cookie.max_age, None);
+            assert_eq!(cookie.same_site, None);
+        }
+
+        #[test]
+        fn it_should_not_parse_a_cookie_without_name() {
+            assert!(Cookie::parse("HttpOnly").is_none());
+            assert!(Cookie::parse("=dark").is_none());
+        }
+    }
+
+    mod impls {
+        use super::*;
+
+        #[test]
+        fn impl_is_eq() {
+            let cookie = Cookie::parse(SESSION).unwrap();
+
+            assert!(cookie.is_eq("abc123").passed());
+            assert!(cookie.is_eq("def456").failed());
+            assert!(cookie.is_ne("def456").passed());
+        }
+
+        #[test]
+        fn impl_includes() {
+            let cookie = Cookie::parse(SESSION).unwrap();
+
+            let attributes = cookie_attributes()
+                .value("abc123")
+                .http_only(true)
+                .secure(true)
+                .same_site(SameSite::Lax)
+                .path("/")
+                .domain(".EXAMPLE.com")
+                .max_age(3600)
+                .expires("Wed, 21 Oct 2026 07:28:00 GMT");
+            assert!(cookie.includes(&attributes).passed());
+            assert!(cookie.includes(&cookie_attributes()).passed());
+
+            assert!(cookie
+                .includes(&cookie_attributes().same_site(SameSite::Strict))
+                .failed());
+            assert!(cookie.includes(&cookie_attributes().path("/api")).failed());
+        }
+
+        #[test]
+        fn impl_includes_missing_attribute() {
+            let cookie = Cookie::parse("theme=dark").unwrap();
+
+            assert!(cookie
+                .includes(&cookie_attributes().http_only(false))
+                .passed());
+            assert!(cookie
+                .includes(&cookie_attributes().domain("example.com"))
+                .failed());
+            assert!(cookie.includes(&cookie_attributes().max_age(0)).failed());
+        }
+
+        #[test]
+        fn impl_existence() {
+            assert!(Cookie::parse("theme=dark").is_present().passed());
+            assert!(None::<Cookie>.is_absent().passed());
+            assert!(None::<Cookie>.is_present().failed());
+        }
+
+        #[test]
+        fn it_should_only_debug_set_attributes() {
+            let attributes = cookie_attributes().http_only(true).path("/");
+
+            assert_eq!(
+                format!("{attributes:?}"),
+                "Cookie { http_only: true, path: \"/\" }"
+            );
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use http::HeaderValue;
+use regex::Regex;
 
-use crate::assertion::traits::{Container, Equality};
-use crate::assertion::types::{Header, HeaderStrTupleVec, HeaderTupleVec, Headers};
+use crate::assertion::traits::{Affix, Container, Equality, Existence, Matching};
+use crate::assertion::types::{Header, HeaderStrTupleVec, HeaderTupleVec, HeaderValues, Headers};
 use crate::assertion::{Assertion, AssertionResult, Hand, UnprocessableReason};
 use crate::dsl::{Part, Predicate};
 use crate::header::HeaderMap;
 
+use super::compile_regex;
+
 /// Converts [`HeaderTupleVec`] into [`Headers`].
 pub fn from_header_tuple_vec(header_tuple_vec: &HeaderTupleVec) -> Headers {
     header_tuple_vec
@@ -488,6 +491,220 @@ impl Equality<HeaderValue> for HeaderValue {
     }
 }
 
+/// Checks the header value with the given function. The expected value is
+/// described by `rhs` in the right hand of the assertion.
+///
+/// The assertion is unprocessable when the header value isn't a visible ASCII
+/// string.
+fn check_header_str<F>(
+    header_value: &HeaderValue,
+    predicate: Predicate,
+    rhs: &str,
+    check: F,
+) -> Assertion<Header>
+where
+    F: Fn(&str) -> bool,
+{
+    let lhs = match header_value.to_str() {
+        Ok(lhs) => lhs,
+        Err(err) => {
+            return Assertion {
+                part: Part::Header,
+                predicate,
+                left: Hand::Empty,
+                right: Hand::Right(rhs.to_string()),
+                result: AssertionResult::Unprocessable(UnprocessableReason::InvalidHeaderValue(
+                    err.to_string(),
+                )),
+            }
+        }
+    };
+
+    Assertion {
+        predicate,
+        part: Part::Header,
+        left: Hand::Left(lhs.to_string()),
+        right: Hand::Right(rhs.to_string()),
+        result: check(lhs).into(),
+    }
+}
+
+impl Container<str> for HeaderValue {
+    type Assertion = Assertion<Header>;
+
+    fn has(&self, rhs: &str) -> Self::Assertion {
+        check_header_str(self, Predicate::Contains, rhs, |lhs| lhs.contains(rhs))
+    }
+
+    fn has_not(&self, rhs: &str) -> Self::Assertion {
+        check_header_str(self, Predicate::DoesNotContain, rhs, |lhs| {
+            !lhs.contains(rhs)
+        })
+    }
+}
+
+impl Affix<str> for HeaderValue {
+    type Assertion = Assertion<Header>;
+
+    fn has_prefix(&self, prefix: &str) -> Self::Assertion {
+        check_header_str(self, Predicate::StartsWith, prefix, |lhs| {
+            lhs.starts_with(prefix)
+        })
+    }
+
+    fn has_suffix(&self, suffix: &str) -> Self::Assertion {
+        check_header_str(self, Predicate::EndsWith, suffix, |lhs| {
+            lhs.ends_with(suffix)
+        })
+    }
+}
+
+impl Matching<str> for HeaderValue {
+    type Assertion = Assertion<Header>;
+
+    fn is_match(&self, re: &str) -> Self::Assertion {
+        let regex: Regex = match compile_regex(re, Predicate::Matches, Part::Header) {
+            Ok(regex) => regex,
+            Err(assertion) => return assertion,
+        };
+
+        check_header_str(self, Predicate::Matches, re, |lhs| regex.is_match(lhs))
+    }
+
+    fn is_not_match(&self, re: &str) -> Self::Assertion {
+        let regex: Regex = match compile_regex(re, Predicate::DoesNotMatch, Part::Header) {
+            Ok(regex) => regex,
+            Err(assertion) => return assertion,
+        };
+
+        check_header_str(self, Predicate::DoesNotMatch, re, |lhs| {
+            !regex.is_match(lhs)
+        })
+    }
+}
+
+impl Existence for Option<HeaderValue> {
+    type Assertion = Assertion<Header>;
+
+    fn is_present(&self) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::Exists,
+            part: Part::Header,
+            left: header_hand(self),
+            right: Hand::Empty,
+            result: self.is_some().into(),
+        }
+    }
+
+    fn is_absent(&self) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::DoesNotExist,
+            part: Part::Header,
+            left: header_hand(self),
+            right: Hand::Empty,
+            result: self.is_none().into(),
+        }
+    }
+}
+
+/// Returns the left hand of an existence test, empty when the header is
+/// missing. The header value is only displayed, the invalid characters are
+/// replaced.
+fn header_hand(header_value: &Option<HeaderValue>) -> Hand<Header> {
+    match header_value {
+        Some(header_value) => {
+            Hand::Left(String::from_utf8_lossy(header_value.as_bytes()).to_string())
+        }
+        None => Hand::Empty,
+    }
+}
+
+/// Converts the header values into strings. Returns the error of the first
+/// header value that isn't a visible ASCII string.
+fn to_header_values(header_values: &[HeaderValue]) -> Result<HeaderValues, String> {
+    header_values
+        .iter()
+        .map(|header_value| {
+            header_value
+                .to_str()
+                .map(str::to_string)
+                .map_err(|err| err.to_string())
+        })
+        .collect()
+}
+
+/// Checks the values of a header with the given function. The expected value
+/// is the right hand of the assertion.
+///
+/// The assertion is unprocessable when a header value isn't a visible ASCII
+/// string.
+fn check_header_values<F>(
+    header_values: &[HeaderValue],
+    predicate: Predicate,
+    rhs: HeaderValues,
+    check: F,
+) -> Assertion<HeaderValues>
+where
+    F: Fn(&HeaderValues) -> bool,
+{
+    let lhs = match to_header_values(header_values) {
+        Ok(lhs) => lhs,
+        Err(err) => {
+            return Assertion {
+                part: Part::Header,
+                predicate,
+                left: Hand::Empty,
+                right: Hand::Right(rhs),
+                result: AssertionResult::Unprocessable(UnprocessableReason::InvalidHeaderValue(
+                    err,
+                )),
+            }
+        }
+    };
+    let result = check(&lhs);
+
+    Assertion {
+        predicate,
+        part: Part::Header,
+        left: Hand::Left(lhs),
+        right: Hand::Right(rhs),
+        result: result.into(),
+    }
+}
+
+impl Equality<HeaderValues> for Vec<HeaderValue> {
+    type Assertion = Assertion<HeaderValues>;
+
+    /// The values are compared in the order they were received.
+    fn is_eq(&self, rhs: &HeaderValues) -> Self::Assertion {
+        check_header_values(self, Predicate::Is, rhs.clone(), |lhs| lhs == rhs)
+    }
+
+    fn is_ne(&self, rhs: &HeaderValues) -> Self::Assertion {
+        check_header_values(self, Predicate::IsNot, rhs.clone(), |lhs| lhs != rhs)
+    }
+}
+
+impl Container<str> for Vec<HeaderValue> {
+    type Assertion = Assertion<HeaderValues>;
+
+    /// Asserts that one of the header values is equal to `rhs`.
+    fn has(&self, rhs: &str) -> Self::Assertion {
+        check_header_values(self, Predicate::Contains, vec![rhs.to_string()], |lhs| {
+            lhs.iter().any(|value| value == rhs)
+        })
+    }
+
+    fn has_not(&self, rhs: &str) -> Self::Assertion {
+        check_header_values(
+            self,
+            Predicate::DoesNotContain,
+            vec![rhs.to_string()],
+            |lhs| lhs.iter().all(|value| value != rhs),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{from_header_map, HeaderTupleVec, Headers};
@@ -872,6 +1089,123 @@ mod tests {
         }
     }
 
+    mod header_value {
+        use super::*;
+        use crate::assertion::{
+            traits::{Affix, Matching},
+            AssertionResult, UnprocessableReason,
+        };
+
+        #[test]
+        fn impl_has_header_value() {
+            let header_value = HeaderValue::from_static("application/json; charset=utf-8");
+
+            assert!(header_value.has("application/json").passed());
+            assert!(header_value.has_not("text/html").passed());
+            assert!(header_value.has("text/html").failed());
+        }
+
+        #[test]
+        fn impl_affix_header_value() {
+            let header_value = HeaderValue::from_static("/orders/ord_1042");
+
+            assert!(header_value.has_prefix("/orders/").passed());
+            assert!(header_value.has_suffix("ord_1042").passed());
+            assert!(header_value.has_prefix("/users/").failed());
+        }
+
+        #[test]
+        fn impl_match_header_value() {
+            let header_value = HeaderValue::from_static("W/\"5e-1f2d\"");
+
+            assert!(header_value.is_match(r#"^W/"[0-9a-f-]+"$"#).passed());
+            assert!(header_value.is_not_match("^[0-9]+$").passed());
+
+            let assertion = header_value.is_match("^W/(");
+            assert!(matches!(
+                assertion.result,
+                AssertionResult::Unprocessable(UnprocessableReason::InvalidRegex(_))
+            ));
+        }
+
+        #[test]
+        fn it_should_not_process_opaque_header_values() {
+            let header_value = HeaderValue::from_bytes(b"caf\xe9").unwrap();
+
+            let assertion = header_value.has_suffix("\u{e9}");
+            assert!(matches!(
+                assertion.result,
+                AssertionResult::Unprocessable(UnprocessableReason::InvalidHeaderValue(_))
+            ));
+        }
+    }
+
+    mod header_presence {
+        use super::*;
+        use crate::assertion::traits::Existence;
+
+        #[test]
+        fn impl_is_present() {
+            let header_value = Some(HeaderValue::from_static("nginx"));
+
+            assert!(header_value.is_present().passed());
+            assert!(header_value.is_absent().failed());
+        }
+
+        #[test]
+        fn impl_is_absent() {
+            let header_value: Option<HeaderValue> = None;
+
+            assert!(header_value.is_absent().passed());
+            assert!(header_value.is_present().failed());
+        }
+    }
+
+    mod header_values {
+        use super::*;
+        use crate::assertion::{AssertionResult, UnprocessableReason};
+
+        fn vary_values() -> Vec<HeaderValue> {
+            vec![
+                HeaderValue::from_static("Origin"),
+                HeaderValue::from_static("Accept-Encoding"),
+            ]
+        }
+
+        #[test]
+        fn impl_is_eq_header_values() {
+            let expected = vec!["Origin".to_string(), "Accept-Encoding".to_string()];
+            let reversed = vec!["Accept-Encoding".to_string(), "Origin".to_string()];
+
+            assert!(vary_values().is_eq(&expected).passed());
+            assert!(vary_values().is_eq(&reversed).failed());
+            assert!(vary_values().is_ne(&reversed).passed());
+            assert!(Vec::new().is_eq(&vec![]).passed());
+        }
+
+        #[test]
+        fn impl_has_header_values() {
+            assert!(vary_values().has("Accept-Encoding").passed());
+            assert!(vary_values().has("Accept").failed());
+            assert!(vary_values().has_not("Cookie").passed());
+            assert!(Vec::new().has_not("Cookie").passed());
+        }
+
+        #[test]
+        fn it_should_not_process_opaque_header_values() {
+            let header_values = vec![
+                HeaderValue::from_static("Origin"),
+                HeaderValue::from_bytes(b"caf\xe9").unwrap(),
+            ];
+
+            let assertion = header_values.has("Origin");
+            assert!(matches!(
+                assertion.result,
+                AssertionResult::Unprocessable(UnprocessableReason::InvalidHeaderValue(_))
+            ));
+        }
+    }
+
     mod serialization {
         use super::*;
         use serde_json::json;
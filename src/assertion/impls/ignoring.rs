@@ -0,0 +1,237 @@
+//! Exclusion of the values matched by json paths from the json equality
+//! tests, and redaction of these values.
+use crate::{
+    assertion::{Assertion, AssertionResult, Hand, UnprocessableReason},
+    dsl::{Part, Predicate},
+};
+use jsonpath_rust::{JsonPath, JsonPathValue};
+use serde_json::Value;
+use std::str::FromStr;
+
+impl Assertion<Value> {
+    /// Removes the values matched by the json paths from both hands of an
+    /// evaluated json equality test, and evaluates the test again.
+    ///
+    /// The hands of a json path test are the arrays of the found values, the
+    /// json paths apply to each of them.
+    pub(crate) fn ignoring(self, paths: &[String]) -> Self {
+        if paths.is_empty() || !matches!(self.predicate, Predicate::Is | Predicate::IsNot) {
+            return self;
+        }
+        if let AssertionResult::Unprocessable(_) = self.result {
+            return self;
+        }
+
+        let json_paths = match parse(paths) {
+            Ok(json_paths) => json_paths,
+            Err(path) => {
+                return Assertion {
+                    result: AssertionResult::Unprocessable(UnprocessableReason::InvalidJsonPath(
+                        path,
+                    )),
+                    ..self
+                }
+            }
+        };
+
+        let (left, right) = match (&self.part, self.left, self.right) {
+            (Part::JsonBody, Hand::Left(actual), Hand::Right(expected)) => (
+                Hand::Left(prune(&actual, &json_paths)),
+                prune(&expected, &json_paths),
+            ),
+            (Part::JsonPath, Hand::Compound(path, actual), Hand::Right(expected)) => (
+                Hand::Compound(path, prune_each(&actual, &json_paths)),
+                prune_each(&expected, &json_paths),
+            ),
+            (_, left, right) => {
+                return Assertion {
+                    left,
+                    right,
+                    ..self
+                }
+            }
+        };
+
+        let actual = match &left {
+            Hand::Left(actual) | Hand::Compound(_, actual) => actual,
+            _ => unreachable!("The left hand is built above"),
+        };
+        let result = match self.predicate {
+            Predicate::IsNot => actual != &right,
+            _ => actual == &right,
+        };
+
+        Assertion {
+            left,
+            right: Hand::Right(right),
+            result: result.into(),
+            ..self
+        }
+    }
+}
+
+/// Parses the json paths, returns the first invalid path on failure.
+pub(super) fn parse(paths: &[String]) -> Result<Vec<JsonPath<Value>>, String> {
+    paths
+        .iter()
+        .map(|path| JsonPath::from_str(path).map_err(|_| path.clone()))
+        .collect()
+}
+
+/// Returns a copy of the value without the values matched by the json paths.
+/// A matched root value becomes `null`.
+fn prune(value: &Value, json_paths: &[JsonPath<Value>]) -> Value {
+    copy_without(value, &find(value, json_paths))
+}
+
+/// Returns a copy of the value where the values matched by the json paths are
+/// replaced by `"[redacted]"`.
+pub(super) fn redact(value: &Value, json_paths: &[JsonPath<Value>]) -> Value {
+    copy_redacted(value, &find(value, json_paths))
+}
+
+/// Returns the nodes of the value matched by the json paths.
+fn find<'v>(value: &'v Value, json_paths: &'v [JsonPath<Value>]) -> Vec<&'v Value> {
+    json_paths
+        .iter()
+        .flat_map(|json_path| json_path.find_slice(value))
+        .filter_map(|found| match found {
+            JsonPathValue::Slice(found, _) => Some(found),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns `true` if the node is one of the matched nodes. The nodes are
+/// identified by their address in the original value.
+fn is_matched(matched: &[&Value], node: &Value) -> bool {
+    matched.iter().any(|matched| std::ptr::eq(*matched, node))
+}
+
+/// Prunes each item of an array of json path results.
+fn prune_each(values: &Value, json_paths: &[JsonPath<Value>]) -> Value {
+    match values {
+        Value::Array(values) => values
+            .iter()
+            .map(|value| prune(value, json_paths))
+            .collect(),
+        value => prune(value, json_paths),
+    }
+}
+
+/// Copies the value, skipping the ignored nodes.
+fn copy_without(value: &Value, ignored: &[&Value]) -> Value {
+    let is_ignored = |node: &Value| is_matched(ignored, node);
+
+    if is_ignored(value) {
+        return Value::Null;
+    }
+
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(_, node)| !is_ignored(node))
+                .map(|(key, node)| (key.clone(), copy_without(node, ignored)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .filter(|node| !is_ignored(node))
+                .map(|node| copy_without(node, ignored))
+                .collect(),
+        ),
+        value => value.clone(),
+    }
+}
+
+/// Copies the value, replacing the redacted nodes.
+fn copy_redacted(value: &Value, redacted: &[&Value]) -> Value {
+    if is_matched(redacted, value) {
+        return Value::String("[redacted]".to_string());
+    }
+
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, node)| (key.clone(), copy_redacted(node, redacted)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|node| copy_redacted(node, redacted))
+                .collect(),
+        ),
+        value => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertion::{Assertion, AssertionResult, Hand, UnprocessableReason};
+    use crate::dsl::{Part, Predicate};
+    use serde_json::{json, Value};
+
+    fn json_body_assertion(actual: Value, expected: Value) -> Assertion<Value> {
+        let result = actual == expected;
+        Assertion {
+            part: Part::JsonBody,
+            predicate: Predicate::Is,
+            left: Hand::Left(actual),
+            right: Hand::Right(expected),
+            result: result.into(),
+        }
+    }
+
+    #[test]
+    fn it_should_ignore_matched_values() {
+        let actual =
+            json!({ "id": 12, "createdAt": "today", "items": [{ "id": 1, "name": "pen" }] });
+        let expected = json!({ "id": 1, "items": [{ "id": 2, "name": "pen" }] });
+
+        let assertion = json_body_assertion(actual, expected)
+            .ignoring(&["$.createdAt".to_string(), "$..id".to_string()]);
+
+        assert!(assertion.passed(), "{}", assertion.log());
+        assert_eq!(
+            serde_json::to_value(&assertion.left).unwrap(),
+            json!({ "items": [{ "name": "pen" }] })
+        );
+    }
+
+    #[test]
+    fn it_should_still_fail_on_other_values() {
+        let actual = json!({ "id": 12, "name": "Isaac" });
+        let expected = json!({ "id": 1, "name": "Max" });
+
+        let assertion = json_body_assertion(actual, expected).ignoring(&["$.id".to_string()]);
+
+        assert!(assertion.failed());
+        assert_eq!(assertion.diff().unwrap().changed[0].pointer, "/name");
+    }
+
+    #[test]
+    fn it_should_ignore_values_of_each_json_path_result() {
+        let assertion = Assertion {
+            part: Part::JsonPath,
+            predicate: Predicate::IsNot,
+            left: Hand::Compound(json!("$.user"), json!([{ "id": 12, "name": "Isaac" }])),
+            right: Hand::Right(json!([{ "id": 1, "name": "Isaac" }])),
+            result: AssertionResult::Passed,
+        };
+
+        let assertion = assertion.ignoring(&["$.id".to_string()]);
+        assert!(assertion.failed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn it_should_be_unprocessable_with_an_invalid_json_path() {
+        let assertion = json_body_assertion(json!({}), json!({})).ignoring(&["$[".to_string()]);
+
+        assert!(matches!(
+            assertion.result,
+            AssertionResult::Unprocessable(UnprocessableReason::InvalidJsonPath(_))
+        ));
+    }
+}
@@ -1,14 +1,17 @@
 use crate::{
     assertion::{
-        traits::{Equality, JsonSchema},
+        diff::JsonDiff,
+        traits::{Equality, HasLength, Inclusion, JsonSchema, OfType, UnorderedEquality},
         Assertion, AssertionResult, Hand, UnprocessableReason,
     },
-    dsl::{Part, Predicate},
+    dsl::{JsonType, Part, Predicate, Subset},
 };
 use jsonschema::{output::BasicOutput, validator_for};
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::{fs, path::PathBuf};
 
+use super::{check_length, json_length, subset};
+
 impl Equality<Value> for Value {
     type Assertion = Assertion<Value>;
 
@@ -37,6 +40,91 @@ impl Equality<Value> for Value {
     }
 }
 
+impl UnorderedEquality<Value> for Value {
+    type Assertion = Assertion<Value>;
+
+    fn unordered_eq(&self, rhs: &Value) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::IsUnordered,
+            part: Part::JsonBody,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(rhs.clone()),
+            result: JsonDiff::unordered(rhs, self).is_empty().into(),
+        }
+    }
+}
+
+impl UnorderedEquality<str> for Value {
+    type Assertion = Assertion<Value>;
+
+    fn unordered_eq(&self, rhs: &str) -> Self::Assertion {
+        let rhs: Value = match serde_json::from_str(rhs) {
+            Ok(value) => value,
+            Err(err) => {
+                return Assertion {
+                    predicate: Predicate::IsUnordered,
+                    part: Part::JsonBody,
+                    left: Hand::Empty,
+                    right: Hand::Empty,
+                    result: AssertionResult::Unprocessable(
+                        UnprocessableReason::SerializationFailure(err.to_string()),
+                    ),
+                }
+            }
+        };
+        self.unordered_eq(&rhs)
+    }
+}
+
+impl UnorderedEquality<String> for Value {
+    type Assertion = Assertion<Value>;
+
+    fn unordered_eq(&self, rhs: &String) -> Self::Assertion {
+        self.unordered_eq(rhs.as_str())
+    }
+}
+
+impl UnorderedEquality<PathBuf> for Value {
+    type Assertion = Assertion<Value>;
+
+    fn unordered_eq(&self, json_file: &PathBuf) -> Self::Assertion {
+        let json_file = match fs::read_to_string(json_file) {
+            Ok(content) => content,
+            Err(_) => {
+                return Assertion {
+                    predicate: Predicate::IsUnordered,
+                    part: Part::JsonBody,
+                    left: Hand::Empty,
+                    right: Hand::Empty,
+                    result: AssertionResult::Unprocessable(UnprocessableReason::Other(format!(
+                        "Failed to read json file located at {}",
+                        json_file.display()
+                    ))),
+                }
+            }
+        };
+
+        let expected_json: Value = match serde_json::from_str(&json_file) {
+            Ok(json) => json,
+            Err(_) => {
+                return Assertion {
+                    predicate: Predicate::IsUnordered,
+                    part: Part::JsonBody,
+                    left: Hand::Empty,
+                    right: Hand::Empty,
+                    result: AssertionResult::Unprocessable(
+                        UnprocessableReason::SerializationFailure(
+                            "Failed to serialize file content".to_string(),
+                        ),
+                    ),
+                }
+            }
+        };
+
+        self.unordered_eq(&expected_json)
+    }
+}
+
 impl Equality<str> for Value {
     type Assertion = Assertion<Value>;
 
@@ -330,6 +418,88 @@ impl JsonSchema<PathBuf> for Value {
     }
 }
 
+impl HasLength<Value> for Value {
+    type Assertion = Assertion<Value>;
+
+    fn length_eq(&self, length: &Value) -> Self::Assertion {
+        measure_length(self, &[length])
+    }
+
+    fn length_in_range(&self, min: &Value, max: &Value) -> Self::Assertion {
+        measure_length(self, &[min, max])
+    }
+}
+
+impl Inclusion<Subset<Value>> for Value {
+    type Assertion = Assertion<Value>;
+
+    /// The left hand of the assertion is composed of the actual value and the
+    /// json pointers of the expected subset that are missing or different.
+    fn includes(&self, subset: &Subset<Value>) -> Self::Assertion {
+        let mismatches = subset::mismatches(self, &subset.value, subset.arrays);
+
+        Assertion {
+            predicate: Predicate::Includes,
+            part: Part::JsonBody,
+            left: Hand::Compound(self.clone(), mismatches.to_json()),
+            right: Hand::Right(subset.value.clone()),
+            result: mismatches.is_empty().into(),
+        }
+    }
+}
+
+impl OfType<JsonType> for Value {
+    type Assertion = Assertion<Value>;
+
+    fn of_type(&self, expected: &JsonType) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::IsType,
+            part: Part::JsonBody,
+            left: Hand::Left(json!(JsonType::of(self))),
+            right: Hand::Right(json!(expected)),
+            result: expected.matches(self).into(),
+        }
+    }
+}
+
+/// Checks the length of the json body against the given bounds. The left hand
+/// of the assertion holds the measured length.
+///
+/// The assertion is unprocessable when the body isn't an array, an object or
+/// a string, or when a bound isn't a number.
+fn measure_length(actual: &Value, bounds: &[&Value]) -> Assertion<Value> {
+    let right = match bounds {
+        [bound] => (*bound).clone(),
+        _ => Value::Array(bounds.iter().map(|&bound| bound.clone()).collect()),
+    };
+    let unprocessable = |reason: UnprocessableReason| Assertion {
+        predicate: Predicate::HasLength,
+        part: Part::JsonBody,
+        left: Hand::Left(actual.clone()),
+        right: Hand::Right(right.clone()),
+        result: AssertionResult::Unprocessable(reason),
+    };
+
+    let Some(length) = json_length(actual) else {
+        return unprocessable(UnprocessableReason::UnmeasurableLength(format!(
+            "{actual} has no length"
+        )));
+    };
+
+    match check_length(length, bounds) {
+        Ok(result) => Assertion {
+            predicate: Predicate::HasLength,
+            part: Part::JsonBody,
+            left: Hand::Left(json!(length)),
+            right: Hand::Right(right),
+            result: result.into(),
+        },
+        Err(bound) => unprocessable(UnprocessableReason::NotANumber(format!(
+            "expected length {bound} is not a number"
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertion::traits::Equality;
@@ -664,4 +834,99 @@ mod tests {
             assert_eq!(log, "Invalid json schema: /properties/age/required => true");
         }
     }
+
+    mod length {
+        use crate::assertion::traits::HasLength;
+        use serde_json::json;
+
+        #[test]
+        fn impl_length_eq() {
+            assert!(json!([1, 2, 3]).length_eq(&json!(3)).passed());
+            assert!(json!({ "a": 1 }).length_eq(&json!(1)).passed());
+            assert!(json!("café").length_eq(&json!(4)).passed());
+            assert!(json!([]).length_eq(&json!(1)).failed());
+        }
+
+        #[test]
+        fn impl_length_in_range() {
+            assert!(json!([1, 2, 3])
+                .length_in_range(&json!(1), &json!(3))
+                .passed());
+            assert!(json!([1, 2, 3])
+                .length_in_range(&json!(4), &json!(10))
+                .failed());
+        }
+
+        #[test]
+        fn impl_length_logs_actual_length() {
+            let assertion = json!([1, 2, 3]).length_eq(&json!(20));
+            assert_eq!(
+                assertion.log(),
+                "result: failed\npart: json body\nshould have length: Number(20)\nwas: Number(3)"
+            );
+        }
+
+        #[test]
+        fn impl_length_of_number_is_unprocessable() {
+            let assertion = json!(12).length_eq(&json!(2));
+            assert_eq!(assertion.log(), "Unprocessable length: 12 has no length");
+        }
+    }
+
+    mod of_type {
+        use crate::assertion::traits::OfType;
+        use crate::dsl::JsonType;
+        use serde_json::json;
+
+        #[test]
+        fn impl_of_type() {
+            assert!(json!([1, 2]).of_type(&JsonType::Array).passed());
+            assert!(json!(1).of_type(&JsonType::Number).passed());
+            assert!(json!(1.5).of_type(&JsonType::Integer).failed());
+        }
+
+        #[test]
+        fn impl_of_type_logs_actual_type() {
+            let assertion = json!({ "id": 1 }).of_type(&JsonType::Array);
+            assert_eq!(
+                assertion.log(),
+                "result: failed\npart: json body\nshould be of type: String(\"array\")\nwas: String(\"object\")"
+            );
+        }
+    }
+
+    mod includes {
+        use crate::assertion::traits::Inclusion;
+        use crate::dsl::{ArrayMatching, Subset};
+        use serde_json::json;
+
+        #[test]
+        fn impl_includes() {
+            let subset = Subset {
+                value: json!({ "user": { "name": "Isaac" } }),
+                arrays: ArrayMatching::Exact,
+            };
+
+            let assertion = json!({ "id": 1, "user": { "name": "Isaac" } }).includes(&subset);
+            assert!(assertion.passed(), "{}", assertion.log());
+        }
+
+        #[test]
+        fn impl_includes_logs_mismatches() {
+            let subset = Subset {
+                value: json!({ "id": 2, "tags": ["a"], "user": { "name": "Isaac" } }),
+                arrays: ArrayMatching::AnyOrder,
+            };
+
+            let assertion = json!({ "id": 1, "tags": ["b"] }).includes(&subset);
+            assert!(assertion.failed());
+            assert!(
+                assertion
+                    .log()
+                    .ends_with("missing: \"/tags/0\", \"/user\"\ndifferent: \"/id\""),
+                "{}",
+                assertion.log()
+            );
+        }
+    }
 }
@@ -1,14 +1,20 @@
 use crate::{
     assertion::{
-        traits::{Container, Equality, JsonSchema, Matching},
+        diff::JsonDiff,
+        traits::{
+            ApproxEquality, Container, Equality, Existence, GreaterOrEqual, GreaterThan, HasLength,
+            JsonSchema, LessOrEqual, LessThan, Matching, OfType, RangeInclusive, UnorderedEquality,
+        },
         Assertion, AssertionResult, Hand, UnprocessableReason,
     },
-    dsl::{json_path::JsonPathResult, Part, Predicate},
+    dsl::{json_path::JsonPathResult, JsonType, Part, Predicate},
 };
 use jsonschema::{output::BasicOutput, Validator};
 use regex::Regex;
 use serde_json::{json, Value};
-use std::{fs, path::PathBuf};
+use std::{cmp::Ordering, fs, path::PathBuf};
+
+use super::{check_length, cmp_numbers, compile_regex, json_length, tolerance};
 
 impl Equality<Value> for JsonPathResult<'_, Value> {
     type Assertion = Assertion<Value>;
@@ -39,6 +45,114 @@ impl Equality<Value> for JsonPathResult<'_, Value> {
     }
 }
 
+impl ApproxEquality<Value> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    /// The expected numbers within the tolerance are replaced by the found
+    /// ones in the right hand, so only the numbers out of the tolerance
+    /// remain in the diff.
+    fn approx_eq(&self, expected: &Value, epsilon: f64) -> Self::Assertion {
+        let expected = tolerance::resolve(&self.value, &to_value_array(expected), epsilon, false);
+        let result = self.value == expected;
+
+        Assertion {
+            predicate: Predicate::IsApprox,
+            part: Part::JsonPath,
+            left: Hand::Compound(Value::String(self.path.to_string()), self.value.clone()),
+            right: Hand::Right(expected),
+            result: result.into(),
+        }
+    }
+}
+
+impl UnorderedEquality<Value> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn unordered_eq(&self, expected: &Value) -> Self::Assertion {
+        let expected = to_value_array(expected);
+        let result = JsonDiff::unordered(&expected, &self.value).is_empty();
+
+        Assertion {
+            predicate: Predicate::IsUnordered,
+            part: Part::JsonPath,
+            left: Hand::Compound(Value::String(self.path.to_string()), self.value.clone()),
+            right: Hand::Right(expected),
+            result: result.into(),
+        }
+    }
+}
+
+impl UnorderedEquality<str> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn unordered_eq(&self, expected: &str) -> Self::Assertion {
+        let expected: Value = match serde_json::from_str(expected) {
+            Ok(value) => value,
+            Err(err) => {
+                return Assertion {
+                    predicate: Predicate::IsUnordered,
+                    part: Part::JsonPath,
+                    left: Hand::Empty,
+                    right: Hand::Empty,
+                    result: AssertionResult::Unprocessable(
+                        UnprocessableReason::SerializationFailure(err.to_string()),
+                    ),
+                }
+            }
+        };
+        self.unordered_eq(&expected)
+    }
+}
+
+impl UnorderedEquality<String> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn unordered_eq(&self, expected: &String) -> Self::Assertion {
+        self.unordered_eq(expected.as_str())
+    }
+}
+
+impl UnorderedEquality<PathBuf> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn unordered_eq(&self, json_file: &PathBuf) -> Self::Assertion {
+        let json_file = match fs::read_to_string(json_file) {
+            Ok(content) => content,
+            Err(_) => {
+                return Assertion {
+                    predicate: Predicate::IsUnordered,
+                    part: Part::JsonPath,
+                    left: Hand::Empty,
+                    right: Hand::Empty,
+                    result: AssertionResult::Unprocessable(UnprocessableReason::Other(format!(
+                        "Failed to read json file located at {}",
+                        json_file.display()
+                    ))),
+                }
+            }
+        };
+
+        let expected_json: Value = match serde_json::from_str(&json_file) {
+            Ok(json) => json,
+            Err(_) => {
+                return Assertion {
+                    predicate: Predicate::IsUnordered,
+                    part: Part::JsonPath,
+                    left: Hand::Empty,
+                    right: Hand::Empty,
+                    result: AssertionResult::Unprocessable(
+                        UnprocessableReason::SerializationFailure(
+                            "Failed to serialize json file content".to_string(),
+                        ),
+                    ),
+                }
+            }
+        };
+
+        self.unordered_eq(&expected_json)
+    }
+}
+
 impl Equality<String> for JsonPathResult<'_, Value> {
     type Assertion = Assertion<Value>;
 
@@ -526,19 +640,9 @@ impl Matching<str> for JsonPathResult<'_, Value> {
     type Assertion = Assertion<Value>;
 
     fn is_match(&self, re: &str) -> Self::Assertion {
-        let regex = match Regex::new(re) {
+        let regex: Regex = match compile_regex(re, Predicate::Matches, Part::JsonPath) {
             Ok(regex) => regex,
-            Err(_) => {
-                return Assertion {
-                    predicate: Predicate::Matches,
-                    part: Part::JsonPath,
-                    left: Hand::Empty,
-                    right: Hand::Empty,
-                    result: AssertionResult::Unprocessable(UnprocessableReason::InvalidRegex(
-                        re.to_string(),
-                    )),
-                }
-            }
+            Err(assertion) => return assertion,
         };
 
         let result = match &self.value {
@@ -567,19 +671,9 @@ impl Matching<str> for JsonPathResult<'_, Value> {
     }
 
     fn is_not_match(&self, re: &str) -> Self::Assertion {
-        let regex = match Regex::new(re) {
+        let regex: Regex = match compile_regex(re, Predicate::DoesNotMatch, Part::JsonPath) {
             Ok(regex) => regex,
-            Err(_) => {
-                return Assertion {
-                    predicate: Predicate::DoesNotMatch,
-                    part: Part::JsonPath,
-                    left: Hand::Empty,
-                    right: Hand::Empty,
-                    result: AssertionResult::Unprocessable(UnprocessableReason::InvalidRegex(
-                        re.to_string(),
-                    )),
-                }
-            }
+            Err(assertion) => return assertion,
         };
 
         let result = match &self.value {
@@ -608,6 +702,238 @@ impl Matching<str> for JsonPathResult<'_, Value> {
     }
 }
 
+impl Existence for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn is_present(&self) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::Exists,
+            part: Part::JsonPath,
+            left: Hand::Compound(Value::String(self.path.to_string()), self.value.clone()),
+            right: Hand::Empty,
+            result: self.found().into(),
+        }
+    }
+
+    fn is_absent(&self) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::DoesNotExist,
+            part: Part::JsonPath,
+            left: Hand::Compound(Value::String(self.path.to_string()), self.value.clone()),
+            right: Hand::Empty,
+            result: (!self.found()).into(),
+        }
+    }
+}
+
+impl LessThan<Value> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn less_than(&self, other: &Value) -> Self::Assertion {
+        compare_numbers(self, Predicate::LessThan, &[other], |ord| ord[0].is_lt())
+    }
+}
+
+impl LessOrEqual<Value> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn less_or_equal(&self, other: &Value) -> Self::Assertion {
+        compare_numbers(self, Predicate::LessOrEqual, &[other], |ord| ord[0].is_le())
+    }
+}
+
+impl GreaterThan<Value> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn greater_than(&self, other: &Value) -> Self::Assertion {
+        compare_numbers(self, Predicate::GreaterThan, &[other], |ord| ord[0].is_gt())
+    }
+}
+
+impl GreaterOrEqual<Value> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn greater_or_equal(&self, other: &Value) -> Self::Assertion {
+        compare_numbers(self, Predicate::GreaterOrEqual, &[other], |ord| {
+            ord[0].is_ge()
+        })
+    }
+}
+
+impl RangeInclusive<Value> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn in_range(&self, min: &Value, max: &Value) -> Self::Assertion {
+        compare_numbers(self, Predicate::Between, &[min, max], |ord| {
+            ord[0].is_ge() && ord[1].is_le()
+        })
+    }
+}
+
+impl OfType<JsonType> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    /// Every node found at the json path must be of the expected type. The
+    /// left hand of the assertion holds the types of the found nodes.
+    fn of_type(&self, expected: &JsonType) -> Self::Assertion {
+        let nodes = match &self.value {
+            Value::Array(nodes) => nodes.iter().collect(),
+            Value::Null => vec![],
+            node => vec![node],
+        };
+        let result = !nodes.is_empty() && nodes.iter().all(|node| expected.matches(node));
+        let types = nodes.into_iter().map(|node| json!(JsonType::of(node)));
+
+        Assertion {
+            predicate: Predicate::IsType,
+            part: Part::JsonPath,
+            left: Hand::Compound(Value::String(self.path.to_string()), types.collect()),
+            right: Hand::Right(json!(expected)),
+            result: result.into(),
+        }
+    }
+}
+
+impl HasLength<Value> for JsonPathResult<'_, Value> {
+    type Assertion = Assertion<Value>;
+
+    fn length_eq(&self, length: &Value) -> Self::Assertion {
+        measure_length(self, &[length])
+    }
+
+    fn length_in_range(&self, min: &Value, max: &Value) -> Self::Assertion {
+        measure_length(self, &[min, max])
+    }
+}
+
+/// Checks the length of the node found at the json path against the given
+/// bounds. The left hand of the assertion holds the measured length.
+///
+/// The assertion is unprocessable when the path doesn't resolve to exactly
+/// one array, object or string, or when a bound isn't a number.
+fn measure_length(jsonpath_res: &JsonPathResult<'_, Value>, bounds: &[&Value]) -> Assertion<Value> {
+    let path = jsonpath_res.path;
+    let right = match bounds {
+        [bound] => (*bound).clone(),
+        _ => Value::Array(bounds.iter().map(|&bound| bound.clone()).collect()),
+    };
+    let assertion = |left: Value, result: AssertionResult| Assertion {
+        predicate: Predicate::HasLength,
+        part: Part::JsonPath,
+        left: Hand::Compound(Value::String(path.to_string()), left),
+        right: Hand::Right(right.clone()),
+        result,
+    };
+    let unprocessable = |reason: UnprocessableReason| {
+        assertion(
+            jsonpath_res.value.clone(),
+            AssertionResult::Unprocessable(reason),
+        )
+    };
+
+    let node = match &jsonpath_res.value {
+        Value::Array(nodes) if nodes.len() == 1 => &nodes[0],
+        Value::Array(nodes) if nodes.len() > 1 => {
+            return unprocessable(UnprocessableReason::UnmeasurableLength(format!(
+                "json path '{path}' matches {} nodes instead of one",
+                nodes.len()
+            )))
+        }
+        Value::Array(_) | Value::Null => {
+            return unprocessable(UnprocessableReason::UnmeasurableLength(format!(
+                "no value found at json path '{path}'"
+            )))
+        }
+        node => node,
+    };
+
+    let Some(length) = json_length(node) else {
+        return unprocessable(UnprocessableReason::UnmeasurableLength(format!(
+            "{node} found at json path '{path}' has no length"
+        )));
+    };
+
+    match check_length(length, bounds) {
+        Ok(result) => assertion(json!(length), result.into()),
+        Err(bound) => unprocessable(UnprocessableReason::NotANumber(format!(
+            "expected length {bound} is not a number"
+        ))),
+    }
+}
+
+/// Compares every number found at the json path with the given bounds. The
+/// `check` function receives the ordering of a found number against each
+/// bound and the assertion passes when it holds for all the found numbers.
+///
+/// The assertion is unprocessable when the path resolves to nothing, or when
+/// a found value or a bound isn't a number.
+fn compare_numbers<F>(
+    jsonpath_res: &JsonPathResult<'_, Value>,
+    predicate: Predicate,
+    bounds: &[&Value],
+    check: F,
+) -> Assertion<Value>
+where
+    F: Fn(&[Ordering]) -> bool,
+{
+    let right = match bounds {
+        [bound] => (*bound).clone(),
+        _ => Value::Array(bounds.iter().map(|&bound| bound.clone()).collect()),
+    };
+    let assertion = |result: AssertionResult| Assertion {
+        predicate: predicate.clone(),
+        part: Part::JsonPath,
+        left: Hand::Compound(
+            Value::String(jsonpath_res.path.to_string()),
+            jsonpath_res.value.clone(),
+        ),
+        right: Hand::Right(right.clone()),
+        result,
+    };
+    let unprocessable = |details: String| {
+        assertion(AssertionResult::Unprocessable(
+            UnprocessableReason::NotANumber(details),
+        ))
+    };
+
+    let mut expected = Vec::with_capacity(bounds.len());
+    for bound in bounds {
+        match bound {
+            Value::Number(number) => expected.push(number),
+            _ => return unprocessable(format!("expected value {bound} is not a number")),
+        }
+    }
+
+    let found = match &jsonpath_res.value {
+        Value::Array(items) => items.iter().collect(),
+        Value::Null => vec![],
+        value => vec![value],
+    };
+    if found.is_empty() {
+        return unprocessable(format!(
+            "no value found at json path '{}'",
+            jsonpath_res.path
+        ));
+    }
+
+    let mut result = true;
+    for value in found {
+        let Value::Number(number) = value else {
+            return unprocessable(format!(
+                "{value} found at json path '{}' is not a number",
+                jsonpath_res.path
+            ));
+        };
+        let orderings: Vec<Ordering> = expected
+            .iter()
+            .map(|bound| cmp_numbers(number, bound))
+            .collect();
+        result &= check(&orderings);
+    }
+
+    assertion(result.into())
+}
+
 /// Make sure the given `Value` will be a `Value::Array` variant
 /// to compare with a `JsonPathResult`; a wrapper around the result
 /// returned by the jsonpath library.
@@ -1284,4 +1610,263 @@ mod tests {
             );
         }
     }
+
+    mod numbers {
+        use super::{json_stub, JsonPathResult};
+        use crate::assertion::{
+            traits::{GreaterOrEqual, GreaterThan, LessOrEqual, LessThan, RangeInclusive},
+            AssertionResult, UnprocessableReason,
+        };
+        use jsonpath_rust::JsonPathQuery;
+        use serde_json::json;
+
+        #[test]
+        fn impl_compare_integers() {
+            let path = "$.shop.total";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            assert!(jsonpath_result.greater_than(&json!(3)).passed());
+            assert!(jsonpath_result.greater_or_equal(&json!(4)).passed());
+            assert!(jsonpath_result.less_than(&json!(5)).passed());
+            assert!(jsonpath_result.less_or_equal(&json!(4)).passed());
+            assert!(jsonpath_result.in_range(&json!(4), &json!(4)).passed());
+            assert!(jsonpath_result.greater_than(&json!(4)).failed());
+            assert!(jsonpath_result.less_than(&json!(4)).failed());
+        }
+
+        #[test]
+        fn impl_compare_integers_and_floats() {
+            let path = "$.shop.total";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            assert!(jsonpath_result.greater_than(&json!(3.99)).passed());
+            assert!(jsonpath_result.less_or_equal(&json!(4.0)).passed());
+            assert!(jsonpath_result.in_range(&json!(3.5), &json!(4.5)).passed());
+            assert!(jsonpath_result.less_than(&json!(-4.1)).failed());
+        }
+
+        #[test]
+        fn impl_compare_every_found_number() {
+            let path = "$.shop.orders[*].id";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            assert!(jsonpath_result.in_range(&json!(1), &json!(4)).passed());
+            assert!(jsonpath_result.greater_than(&json!(1)).failed());
+        }
+
+        #[test]
+        fn impl_compare_large_integers() {
+            let jsonpath_result = JsonPathResult {
+                path: "$.id",
+                value: json!([u64::MAX]),
+            };
+
+            assert!(jsonpath_result.greater_than(&json!(i64::MAX)).passed());
+            assert!(jsonpath_result.less_than(&json!(-1)).failed());
+        }
+
+        #[test]
+        fn impl_compare_non_number_is_unprocessable() {
+            let path = "$.shop.orders[0].active";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            let assertion = jsonpath_result.greater_than(&json!(1));
+            assert!(
+                matches!(
+                    assertion.result,
+                    AssertionResult::Unprocessable(UnprocessableReason::NotANumber(_))
+                ),
+                "{}",
+                assertion.log()
+            );
+            assert_eq!(
+                assertion.log(),
+                "Expected a number: true found at json path '$.shop.orders[0].active' is not a number"
+            );
+        }
+
+        #[test]
+        fn impl_compare_missing_value_is_unprocessable() {
+            let path = "$.shop.unknown";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            let assertion = jsonpath_result.less_than(&json!(1));
+            assert_eq!(
+                assertion.log(),
+                "Expected a number: no value found at json path '$.shop.unknown'"
+            );
+        }
+
+        #[test]
+        fn impl_compare_with_non_number_bound_is_unprocessable() {
+            let path = "$.shop.total";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            let assertion = jsonpath_result.in_range(&json!(1), &json!("10"));
+            assert_eq!(
+                assertion.log(),
+                "Expected a number: expected value \"10\" is not a number"
+            );
+        }
+    }
+
+    mod existence {
+        use super::{json_stub, JsonPathResult};
+        use crate::assertion::traits::Existence;
+        use jsonpath_rust::JsonPathQuery;
+        use serde_json::json;
+
+        #[test]
+        fn impl_is_present() {
+            let path = "$.shop.orders[0].active";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            assert!(jsonpath_result.is_present().passed());
+            assert!(jsonpath_result.is_absent().failed());
+        }
+
+        #[test]
+        fn impl_is_present_with_null() {
+            let jsonpath_result = JsonPathResult {
+                path: "$.coupon",
+                value: json!([null]),
+            };
+
+            assert!(jsonpath_result.is_present().passed());
+        }
+
+        #[test]
+        fn impl_is_absent() {
+            for path in [
+                "$.shop.unknown",
+                "$.shop.orders[*].unknown",
+                "$.shop.orders[9]",
+            ] {
+                let value = json_stub().path(path).unwrap();
+                let jsonpath_result = JsonPathResult { path, value };
+
+                assert!(jsonpath_result.is_absent().passed(), "{path}");
+                assert!(jsonpath_result.is_present().failed(), "{path}");
+            }
+        }
+
+        #[test]
+        fn it_logs_without_expected_value() {
+            let jsonpath_result = JsonPathResult {
+                path: "$.coupon",
+                value: json!([null]),
+            };
+
+            assert_eq!(
+                jsonpath_result.is_absent().log(),
+                "result: failed\npart: json path '$.coupon'\nshould not exist\nwas: Array [\n    Null,\n]"
+            );
+        }
+    }
+
+    mod length {
+        use super::{json_stub, JsonPathResult};
+        use crate::assertion::traits::HasLength;
+        use jsonpath_rust::JsonPathQuery;
+        use serde_json::json;
+
+        #[test]
+        fn impl_length_of_found_node() {
+            let path = "$.shop.orders";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            assert!(jsonpath_result.length_eq(&json!(4)).passed());
+            assert!(jsonpath_result
+                .length_in_range(&json!(1), &json!(3))
+                .failed());
+        }
+
+        #[test]
+        fn impl_length_logs_actual_length() {
+            let path = "$.shop.orders";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            assert_eq!(
+                jsonpath_result.length_eq(&json!(20)).log(),
+                "result: failed\npart: json path '$.shop.orders'\nshould have length: Number(20)\nwas: Number(4)"
+            );
+        }
+
+        #[test]
+        fn impl_length_is_unprocessable() {
+            let cases = [
+                (
+                    "$.shop.total",
+                    "4 found at json path '$.shop.total' has no length",
+                ),
+                (
+                    "$.shop.unknown",
+                    "no value found at json path '$.shop.unknown'",
+                ),
+                (
+                    "$.shop.orders[*]",
+                    "json path '$.shop.orders[*]' matches 4 nodes instead of one",
+                ),
+            ];
+
+            for (path, details) in cases {
+                let value = json_stub().path(path).unwrap();
+                let jsonpath_result = JsonPathResult { path, value };
+
+                assert_eq!(
+                    jsonpath_result.length_eq(&json!(1)).log(),
+                    format!("Unprocessable length: {details}")
+                );
+            }
+        }
+    }
+
+    mod of_type {
+        use super::{json_stub, JsonPathResult};
+        use crate::assertion::traits::OfType;
+        use crate::dsl::JsonType;
+        use jsonpath_rust::JsonPathQuery;
+        use serde_json::json;
+
+        #[test]
+        fn impl_of_type_for_every_node() {
+            let path = "$.shop.orders[*].id";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            assert!(jsonpath_result.of_type(&JsonType::Integer).passed());
+            assert!(jsonpath_result.of_type(&JsonType::String).failed());
+        }
+
+        #[test]
+        fn impl_of_type_without_node_fails() {
+            let path = "$.shop.unknown";
+            let value = json_stub().path(path).unwrap();
+            let jsonpath_result = JsonPathResult { path, value };
+
+            assert!(jsonpath_result.of_type(&JsonType::Null).failed());
+        }
+
+        #[test]
+        fn impl_of_type_logs_actual_types() {
+            let jsonpath_result = JsonPathResult {
+                path: "$.shop.orders[0]",
+                value: json!([{ "id": 1 }]),
+            };
+
+            assert_eq!(
+                jsonpath_result.of_type(&JsonType::Array).log(),
+                "result: failed\npart: json path '$.shop.orders[0]'\nshould be of type: String(\"array\")\nwas: Array [\n    String(\"object\"),\n]"
+            );
+        }
+    }
 }
@@ -1,5 +1,89 @@
+mod body_bytes;
+mod cookie;
 mod header;
+mod ignoring;
 mod json_body;
 mod json_path;
+mod placeholder;
+mod snapshot;
 mod status;
+mod subset;
+mod text_body;
 mod time;
+mod tolerance;
+mod unordered;
+
+use crate::{
+    assertion::{Assertion, AssertionResult, Hand, UnprocessableReason},
+    dsl::{Part, Predicate},
+};
+use serde::Serialize;
+use serde_json::{Number, Value};
+use std::{cmp::Ordering, fmt::Debug, str::FromStr};
+
+/// Compiles the regex of a matching test, either a [`regex::Regex`] or a
+/// [`regex::bytes::Regex`]. An invalid regex is returned as an unprocessable
+/// assertion of the given predicate and part.
+fn compile_regex<R, T>(re: &str, predicate: Predicate, part: Part) -> Result<R, Assertion<T>>
+where
+    R: FromStr<Err = regex::Error>,
+    T: Debug + Serialize,
+{
+    re.parse().map_err(|_| Assertion {
+        predicate,
+        part,
+        left: Hand::Empty,
+        right: Hand::Empty,
+        result: AssertionResult::Unprocessable(UnprocessableReason::InvalidRegex(re.to_string())),
+    })
+}
+
+/// Compares two json numbers. Integers are compared exactly, a float on
+/// either side makes the comparison happen on `f64` values.
+fn cmp_numbers(left: &Number, right: &Number) -> Ordering {
+    let as_integer = |n: &Number| {
+        n.as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from))
+    };
+
+    match (as_integer(left), as_integer(right)) {
+        (Some(left), Some(right)) => left.cmp(&right),
+        _ => {
+            let left = left.as_f64().unwrap_or(f64::NAN);
+            let right = right.as_f64().unwrap_or(f64::NAN);
+            left.partial_cmp(&right).unwrap_or(Ordering::Equal)
+        }
+    }
+}
+
+/// Returns the length of a json array, object or string. Other values don't
+/// have a length.
+fn json_length(value: &Value) -> Option<usize> {
+    match value {
+        Value::Array(items) => Some(items.len()),
+        Value::Object(map) => Some(map.len()),
+        Value::String(text) => Some(text.chars().count()),
+        _ => None,
+    }
+}
+
+/// Checks a length against the expected bounds: a single bound is the exact
+/// expected length, two bounds are a closed interval. Returns the offending
+/// bound when it isn't a number.
+fn check_length(length: usize, bounds: &[&Value]) -> Result<bool, Value> {
+    let length = Number::from(length);
+    let mut orderings = Vec::with_capacity(bounds.len());
+    for bound in bounds {
+        match bound {
+            Value::Number(bound) => orderings.push(cmp_numbers(&length, bound)),
+            _ => return Err((*bound).clone()),
+        }
+    }
+
+    Ok(match orderings.as_slice() {
+        [ordering] => ordering.is_eq(),
+        [min, max] => min.is_ge() && max.is_le(),
+        _ => false,
+    })
+}
@@ -0,0 +1,201 @@
+//! Typed placeholders in the expected json values.
+//!
+//! A string of the expected value made of a single placeholder matches any
+//! actual value of the described shape:
+//!
+//! - `{{any_string}}`: a string,
+//! - `{{uuid}}`: a string formatted as an uuid,
+//! - `{{iso8601}}`: a string formatted as an ISO 8601 date or date time,
+//! - `{{number}}`: a number,
+//! - `{{regex:<pattern>}}`: a string matching the regex pattern.
+//!
+//! Other strings are compared as-is. The placeholders are resolved by the
+//! `is` and `is_not` tests of the json body and json paths, not by the
+//! snapshots.
+use crate::{
+    assertion::{Assertion, AssertionResult, Hand, UnprocessableReason},
+    dsl::Predicate,
+};
+use regex::Regex;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+impl Assertion<Value> {
+    /// Evaluates again an evaluated json equality test, the placeholders of
+    /// the expected hand matching the actual value being replaced by it, so
+    /// only the unmatched placeholders remain in the diff.
+    ///
+    /// An invalid regex placeholder makes the assertion unprocessable.
+    pub(crate) fn with_placeholders(self) -> Self {
+        if !matches!(self.predicate, Predicate::Is | Predicate::IsNot) {
+            return self;
+        }
+        if let AssertionResult::Unprocessable(_) = self.result {
+            return self;
+        }
+
+        let (right, result) = match (&self.left, &self.right) {
+            (Hand::Left(actual) | Hand::Compound(_, actual), Hand::Right(expected)) => {
+                match resolve(actual, expected) {
+                    Ok(expected) => {
+                        let result = match self.predicate {
+                            Predicate::IsNot => actual != &expected,
+                            _ => actual == &expected,
+                        };
+                        (Hand::Right(expected), result.into())
+                    }
+                    Err(pattern) => (
+                        Hand::Right(expected.clone()),
+                        AssertionResult::Unprocessable(UnprocessableReason::InvalidRegex(pattern)),
+                    ),
+                }
+            }
+            _ => return self,
+        };
+
+        Assertion {
+            right,
+            result,
+            ..self
+        }
+    }
+}
+
+fn uuid_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$")
+            .expect("Valid uuid regex")
+    })
+}
+
+fn iso8601_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"^\d{4}-\d{2}-\d{2}(T\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}(:?\d{2})?)?)?$")
+            .expect("Valid ISO 8601 regex")
+    })
+}
+
+enum Placeholder {
+    AnyString,
+    Uuid,
+    Iso8601,
+    Number,
+    Regex(Regex),
+}
+
+impl Placeholder {
+    /// Parses the placeholder of an expected string. Returns `None` if the
+    /// string isn't a placeholder, or the pattern if the regex is invalid.
+    fn parse(text: &str) -> Option<Result<Self, String>> {
+        let name = text.strip_prefix("{{")?.strip_suffix("}}")?;
+
+        let placeholder = match name {
+            "any_string" => Placeholder::AnyString,
+            "uuid" => Placeholder::Uuid,
+            "iso8601" => Placeholder::Iso8601,
+            "number" => Placeholder::Number,
+            name => {
+                let pattern = name.strip_prefix("regex:")?;
+                return Some(
+                    Regex::new(pattern)
+                        .map(Placeholder::Regex)
+                        .map_err(|_| pattern.to_string()),
+                );
+            }
+        };
+
+        Some(Ok(placeholder))
+    }
+
+    fn matches(&self, actual: &Value) -> bool {
+        match (self, actual) {
+            (Placeholder::Number, Value::Number(_)) => true,
+            (Placeholder::AnyString, Value::String(_)) => true,
+            (Placeholder::Uuid, Value::String(text)) => uuid_regex().is_match(text),
+            (Placeholder::Iso8601, Value::String(text)) => iso8601_regex().is_match(text),
+            (Placeholder::Regex(regex), Value::String(text)) => regex.is_match(text),
+            _ => false,
+        }
+    }
+}
+
+/// Returns the expected value where the placeholders matching the actual
+/// value are replaced by the actual value, so both values can be compared
+/// for equality. The placeholders that don't match are left untouched.
+///
+/// Returns the pattern of the first invalid regex placeholder on failure.
+fn resolve(actual: &Value, expected: &Value) -> Result<Value, String> {
+    match (actual, expected) {
+        (actual, Value::String(text)) => match Placeholder::parse(text).transpose()? {
+            Some(placeholder) if placeholder.matches(actual) => Ok(actual.clone()),
+            _ => Ok(expected.clone()),
+        },
+        (Value::Object(actual), Value::Object(expected)) => expected
+            .iter()
+            .map(|(key, expected)| {
+                let value = match actual.get(key) {
+                    Some(actual) => resolve(actual, expected)?,
+                    None => expected.clone(),
+                };
+                Ok((key.clone(), value))
+            })
+            .collect::<Result<_, _>>()
+            .map(Value::Object),
+        (Value::Array(actual), Value::Array(expected)) => expected
+            .iter()
+            .enumerate()
+            .map(|(index, expected)| match actual.get(index) {
+                Some(actual) => resolve(actual, expected),
+                None => Ok(expected.clone()),
+            })
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        (_, expected) => Ok(expected.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_resolve_matching_placeholders() {
+        let actual = json!({
+            "id": "0b6f2b5e-57b5-4bd5-9a38-1a5b3c8f2d10",
+            "name": "Isaac",
+            "createdAt": "2024-05-12T10:42:00.123Z",
+            "price": 9.99,
+            "orders": [{ "ref": "ord_42" }]
+        });
+        let expected = json!({
+            "id": "{{uuid}}",
+            "name": "{{any_string}}",
+            "createdAt": "{{iso8601}}",
+            "price": "{{number}}",
+            "orders": [{ "ref": "{{regex:^ord_[0-9]+$}}" }]
+        });
+
+        assert_eq!(resolve(&actual, &expected), Ok(actual));
+    }
+
+    #[test]
+    fn it_should_keep_mismatching_placeholders() {
+        let actual = json!({ "id": 42, "date": "12/05/2024", "ref": "42", "other": "{{name}}" });
+        let expected = json!({ "id": "{{uuid}}", "date": "{{iso8601}}", "ref": "{{regex:^ord_}}", "other": "{{name}}" });
+
+        assert_eq!(resolve(&actual, &expected), Ok(expected));
+    }
+
+    #[test]
+    fn it_should_fail_with_an_invalid_regex() {
+        let expected = json!({ "ref": "{{regex:(}}" });
+
+        assert_eq!(
+            resolve(&json!({ "ref": "ord_1" }), &expected),
+            Err("(".to_string())
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Snapshot files of json values and headers.
+//!
+//! A snapshot is the pretty-printed json value stored in `<dir>/<name>.json`.
+//! It's written with the actual value the first time the assertion runs, and
+//! compared with the actual value on the next runs. An existing snapshot is
+//! only rewritten when an update is requested with the
+//! `GRILLON_UPDATE_SNAPSHOTS` environment variable.
+//!
+//! The headers are stored as a json object whose keys are the header names in
+//! alphabetical order, and whose values are the arrays of the header values in
+//! the order they were received. They are compared as json values, so a
+//! failure shows the json diff between the header objects.
+use crate::{
+    assertion::{traits::SnapshotMatching, Assertion, AssertionResult, Hand, UnprocessableReason},
+    dsl::{Part, Predicate, Snapshot},
+    header::HeaderMap,
+};
+use serde_json::Value;
+use std::{collections::BTreeMap, env, fs, io, path::PathBuf};
+
+use super::ignoring;
+
+/// The default directory of the snapshot files, relative to the current
+/// directory.
+const DEFAULT_SNAPSHOT_DIR: &str = "tests/snapshots";
+
+/// Returns `true` if the snapshot files are rewritten with the actual values.
+fn update_requested() -> bool {
+    env::var("GRILLON_UPDATE_SNAPSHOTS").is_ok_and(|value| value == "1")
+}
+
+impl SnapshotMatching<Snapshot> for Value {
+    type Assertion = Assertion<Value>;
+
+    fn matches_snapshot(&self, snapshot: &Snapshot) -> Self::Assertion {
+        check(self, snapshot, update_requested())
+    }
+}
+
+impl SnapshotMatching<Snapshot> for HeaderMap {
+    type Assertion = Assertion<Value>;
+
+    fn matches_snapshot(&self, snapshot: &Snapshot) -> Self::Assertion {
+        check_headers(self, snapshot, update_requested())
+    }
+}
+
+fn unprocessable(part: Part, reason: UnprocessableReason) -> Assertion<Value> {
+    Assertion {
+        predicate: Predicate::MatchesSnapshot,
+        part,
+        left: Hand::Empty,
+        right: Hand::Empty,
+        result: AssertionResult::Unprocessable(reason),
+    }
+}
+
+fn check(actual: &Value, snapshot: &Snapshot, update: bool) -> Assertion<Value> {
+    compare(Part::JsonBody, actual, snapshot, update)
+}
+
+fn check_headers(actual: &HeaderMap, snapshot: &Snapshot, update: bool) -> Assertion<Value> {
+    match headers_to_json(actual) {
+        Ok(actual) => compare(Part::Headers, &actual, snapshot, update),
+        Err(reason) => unprocessable(Part::Headers, reason),
+    }
+}
+
+fn compare(part: Part, actual: &Value, snapshot: &Snapshot, update: bool) -> Assertion<Value> {
+    let (actual, expected) = match load(actual, snapshot, update) {
+        Ok(values) => values,
+        Err(reason) => return unprocessable(part, reason),
+    };
+    let result = actual == expected;
+
+    Assertion {
+        predicate: Predicate::MatchesSnapshot,
+        part,
+        left: Hand::Left(actual),
+        right: Hand::Right(expected),
+        result: result.into(),
+    }
+}
+
+/// Redacts the actual value and returns it with the snapshot value. The
+/// snapshot is first written with the redacted value when it's missing or
+/// when an update is requested.
+fn load(
+    actual: &Value,
+    snapshot: &Snapshot,
+    update: bool,
+) -> Result<(Value, Value), UnprocessableReason> {
+    let json_paths =
+        ignoring::parse(&snapshot.redactions).map_err(UnprocessableReason::InvalidJsonPath)?;
+    let actual = ignoring::redact(actual, &json_paths);
+    let path = snapshot_path(snapshot);
+
+    if update || !path.exists() {
+        write(&path, &actual).map_err(|err| {
+            UnprocessableReason::Other(format!(
+                "Failed to write snapshot file at {}: {err}",
+                path.display()
+            ))
+        })?;
+
+        return Ok((actual.clone(), actual));
+    }
+
+    let content = fs::read_to_string(&path).map_err(|_| {
+        UnprocessableReason::Other(format!(
+            "Failed to read snapshot file at {}",
+            path.display()
+        ))
+    })?;
+    let expected = serde_json::from_str(&content).map_err(|_| {
+        UnprocessableReason::SerializationFailure(format!(
+            "Failed to deserialize snapshot file at {}",
+            path.display()
+        ))
+    })?;
+
+    Ok((actual, expected))
+}
+
+/// Converts the headers into a json object, the values of a header are
+/// grouped in an array under its name.
+fn headers_to_json(headers: &HeaderMap) -> Result<Value, UnprocessableReason> {
+    let mut grouped: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (name, value) in headers {
+        let value = value
+            .to_str()
+            .map_err(|_| UnprocessableReason::InvalidHeaderValue(format!("{name}: {value:?}")))?;
+        grouped.entry(name.as_str()).or_default().push(value);
+    }
+
+    Ok(serde_json::json!(grouped))
+}
+
+/// Returns the path of the snapshot file.
+fn snapshot_path(snapshot: &Snapshot) -> PathBuf {
+    let dir = snapshot
+        .dir
+        .clone()
+        .or_else(|| env::var_os("GRILLON_SNAPSHOT_DIR").map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_SNAPSHOT_DIR));
+
+    dir.join(format!("{}.json", snapshot.name))
+}
+
+fn write(path: &PathBuf, value: &Value) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let content = serde_json::to_string_pretty(value)?;
+
+    fs::write(path, content + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, check_headers};
+    use crate::{
+        assertion::{AssertionResult, UnprocessableReason},
+        dsl::Snapshot,
+        header::{HeaderMap, HeaderValue, CONTENT_TYPE, DATE, VARY},
+    };
+    use serde_json::json;
+    use std::{env, fs, path::PathBuf};
+
+    fn snapshot_stub(name: &str) -> Snapshot {
+        let dir = env::temp_dir().join("grillon-snapshots");
+        let _ = fs::remove_file(dir.join(format!("{name}.json")));
+
+        Snapshot {
+            name: name.to_string(),
+            dir: Some(dir),
+            redactions: vec!["$.createdAt".to_string()],
+        }
+    }
+
+    fn snapshot_file(snapshot: &Snapshot) -> PathBuf {
+        snapshot
+            .dir
+            .clone()
+            .unwrap()
+            .join(format!("{}.json", snapshot.name))
+    }
+
+    #[test]
+    fn it_should_write_missing_snapshots() {
+        let snapshot = snapshot_stub("missing");
+        let actual = json!({ "id": 1, "createdAt": "2024-05-12T10:42:00Z" });
+
+        assert!(check(&actual, &snapshot, false).passed());
+        assert_eq!(
+            fs::read_to_string(snapshot_file(&snapshot)).unwrap(),
+            "{\n  \"createdAt\": \"[redacted]\",\n  \"id\": 1\n}\n"
+        );
+        assert!(check(&json!({ "id": 2 }), &snapshot, false).failed());
+    }
+
+    #[test]
+    fn it_should_write_the_snapshot_on_update() {
+        let snapshot = snapshot_stub("write");
+        let actual = json!({ "id": 1, "createdAt": "2024-05-12T10:42:00Z" });
+
+        assert!(check(&actual, &snapshot, true).passed());
+        assert_eq!(
+            fs::read_to_string(snapshot_file(&snapshot)).unwrap(),
+            "{\n  \"createdAt\": \"[redacted]\",\n  \"id\": 1\n}\n"
+        );
+    }
+
+    #[test]
+    fn it_should_compare_with_the_snapshot() {
+        let snapshot = snapshot_stub("compare");
+        check(&json!({ "id": 1, "createdAt": "today" }), &snapshot, true);
+
+        assert!(check(
+            &json!({ "id": 1, "createdAt": "tomorrow" }),
+            &snapshot,
+            false
+        )
+        .passed());
+
+        let assertion = check(&json!({ "id": 2, "createdAt": "today" }), &snapshot, false);
+        assert!(assertion.failed());
+        assert_eq!(assertion.diff().unwrap().changed[0].pointer, "/id");
+    }
+
+    #[test]
+    fn it_should_update_the_snapshot() {
+        let snapshot = snapshot_stub("update");
+        check(&json!({ "id": 1 }), &snapshot, true);
+
+        assert!(check(&json!({ "id": 2 }), &snapshot, true).passed());
+        assert!(check(&json!({ "id": 2 }), &snapshot, false).passed());
+    }
+
+    #[test]
+    fn it_should_compare_placeholders_as_plain_strings() {
+        let snapshot = snapshot_stub("placeholders");
+        check(&json!({ "name": "{{any_string}}" }), &snapshot, true);
+
+        assert!(check(&json!({ "name": "Isaac" }), &snapshot, false).failed());
+    }
+
+    #[test]
+    fn it_should_be_unprocessable_with_an_invalid_redaction() {
+        let mut snapshot = snapshot_stub("invalid");
+        snapshot.redactions = vec!["$[".to_string()];
+
+        assert!(matches!(
+            check(&json!({}), &snapshot, true).result,
+            AssertionResult::Unprocessable(UnprocessableReason::InvalidJsonPath(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_write_sorted_headers() {
+        let mut snapshot = snapshot_stub("headers");
+        snapshot.redactions = vec!["$.date".to_string()];
+        let mut headers = HeaderMap::new();
+        headers.append(VARY, HeaderValue::from_static("Origin"));
+        headers.append(
+            DATE,
+            HeaderValue::from_static("Tue, 12 May 2024 10:42:00 GMT"),
+        );
+        headers.append(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.append(VARY, HeaderValue::from_static("Accept-Encoding"));
+
+        assert!(check_headers(&headers, &snapshot, true).passed());
+        assert_eq!(
+            fs::read_to_string(snapshot_file(&snapshot)).unwrap(),
+            r#"{
+  "content-type": [
+    "application/json"
+  ],
+  "date": "[redacted]",
+  "vary": [
+    "Origin",
+    "Accept-Encoding"
+  ]
+}
+"#
+        );
+
+        headers.insert(
+            DATE,
+            HeaderValue::from_static("Wed, 13 May 2024 08:00:00 GMT"),
+        );
+        assert!(check_headers(&headers, &snapshot, false).passed());
+
+        headers.insert(VARY, HeaderValue::from_static("Origin"));
+        let assertion = check_headers(&headers, &snapshot, false);
+        assert!(assertion.failed());
+        assert_eq!(assertion.diff().unwrap().removed[0].pointer, "/vary/1");
+    }
+
+    #[test]
+    fn it_should_write_missing_headers_snapshots() {
+        let snapshot = snapshot_stub("missing_headers");
+        let mut headers = HeaderMap::new();
+        headers.append(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        assert!(check_headers(&headers, &snapshot, false).passed());
+        assert_eq!(
+            fs::read_to_string(snapshot_file(&snapshot)).unwrap(),
+            "{\n  \"content-type\": [\n    \"application/json\"\n  ]\n}\n"
+        );
+        assert!(check_headers(&HeaderMap::new(), &snapshot, false).failed());
+    }
+}
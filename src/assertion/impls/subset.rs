@@ -0,0 +1,213 @@
+//! Recursive subset matching of json values.
+//!
+//! The mismatches are reported as [json pointers] relative to the expected
+//! value.
+//!
+//! [json pointers]: https://datatracker.ietf.org/doc/html/rfc6901
+use crate::{assertion::diff::escape, dsl::ArrayMatching};
+use serde_json::{json, Value};
+
+/// The json pointers of the expected value that are missing or different in
+/// the actual value.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct Mismatches {
+    pub(super) missing: Vec<String>,
+    pub(super) different: Vec<String>,
+}
+
+impl Mismatches {
+    /// Returns `true` if the expected value is included in the actual one.
+    pub(super) fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.different.is_empty()
+    }
+
+    /// Returns the json representation of the mismatches.
+    pub(super) fn to_json(&self) -> Value {
+        json!({
+            "missing": self.missing,
+            "different": self.different,
+        })
+    }
+}
+
+/// Returns the mismatches between the actual value and the expected subset.
+///
+/// Objects of the actual value can have more keys than the expected ones,
+/// arrays are compared according to the given [`ArrayMatching`] and other
+/// values must be equal.
+pub(super) fn mismatches(actual: &Value, expected: &Value, arrays: ArrayMatching) -> Mismatches {
+    let mut mismatches = Mismatches::default();
+    collect(actual, expected, arrays, String::new(), &mut mismatches);
+
+    mismatches
+}
+
+fn collect(
+    actual: &Value,
+    expected: &Value,
+    arrays: ArrayMatching,
+    pointer: String,
+    mismatches: &mut Mismatches,
+) {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => {
+            for (key, expected) in expected {
+                let pointer = format!("{pointer}/{}", escape(key));
+                match actual.get(key) {
+                    Some(actual) => collect(actual, expected, arrays, pointer, mismatches),
+                    None => mismatches.missing.push(pointer),
+                }
+            }
+        }
+        (Value::Array(actual), Value::Array(expected)) => match arrays {
+            ArrayMatching::Exact | ArrayMatching::Prefix => {
+                if arrays == ArrayMatching::Exact && actual.len() > expected.len() {
+                    mismatches.different.push(pointer.clone());
+                }
+                for (index, expected) in expected.iter().enumerate() {
+                    let pointer = format!("{pointer}/{index}");
+                    match actual.get(index) {
+                        Some(actual) => collect(actual, expected, arrays, pointer, mismatches),
+                        None => mismatches.missing.push(pointer),
+                    }
+                }
+            }
+            ArrayMatching::AnyOrder => {
+                for index in unmatched(actual, expected, arrays) {
+                    mismatches.missing.push(format!("{pointer}/{index}"));
+                }
+            }
+        },
+        (actual, expected) if actual == expected => {}
+        _ => mismatches.different.push(pointer),
+    }
+}
+
+/// Pairs each expected item with a distinct actual item including it, and
+/// returns the indexes of the expected items left without a pair.
+///
+/// A greedy pairing can miss a solution since an actual item may include
+/// several expected items. The pairs are found as a maximum bipartite
+/// matching instead.
+fn unmatched(actual: &[Value], expected: &[Value], arrays: ArrayMatching) -> Vec<usize> {
+    let candidates: Vec<Vec<usize>> = expected
+        .iter()
+        .map(|expected| {
+            actual
+                .iter()
+                .enumerate()
+                .filter(|(_, actual)| mismatches(actual, expected, arrays).is_empty())
+                .map(|(index, _)| index)
+                .collect()
+        })
+        .collect();
+
+    let mut owners: Vec<Option<usize>> = vec![None; actual.len()];
+    for index in 0..expected.len() {
+        let mut visited = vec![false; actual.len()];
+        pair(index, &candidates, &mut owners, &mut visited);
+    }
+
+    (0..expected.len())
+        .filter(|index| !owners.contains(&Some(*index)))
+        .collect()
+}
+
+/// Tries to pair the expected item with an actual one, moving the previous
+/// owners to other candidates when needed.
+fn pair(
+    expected: usize,
+    candidates: &[Vec<usize>],
+    owners: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for &actual in &candidates[expected] {
+        if visited[actual] {
+            continue;
+        }
+        visited[actual] = true;
+
+        let is_free = match owners[actual] {
+            Some(owner) => pair(owner, candidates, owners, visited),
+            None => true,
+        };
+        if is_free {
+            owners[actual] = Some(expected);
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mismatches, Mismatches};
+    use crate::dsl::ArrayMatching;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_include_nested_objects() {
+        let actual = json!({ "id": 1, "user": { "name": "Isaac", "age": 22 }, "active": true });
+        let expected = json!({ "user": { "name": "Isaac" } });
+
+        assert!(mismatches(&actual, &expected, ArrayMatching::Exact).is_empty());
+    }
+
+    #[test]
+    fn it_should_report_missing_and_different_pointers() {
+        let actual = json!({ "id": 1, "user": { "name": "Isaac" } });
+        let expected = json!({ "id": 2, "user": { "name": "Isaac", "a/b": 1 }, "tags": [] });
+
+        assert_eq!(
+            mismatches(&actual, &expected, ArrayMatching::Exact),
+            Mismatches {
+                missing: vec!["/tags".to_string(), "/user/a~1b".to_string()],
+                different: vec!["/id".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_match_exact_arrays() {
+        let actual = json!([{ "id": 1, "name": "Isaac" }, { "id": 2 }]);
+
+        let exact = mismatches(
+            &actual,
+            &json!([{ "id": 1 }, { "id": 2 }]),
+            ArrayMatching::Exact,
+        );
+        assert!(exact.is_empty());
+
+        let prefix = mismatches(&actual, &json!([{ "id": 1 }]), ArrayMatching::Exact);
+        assert_eq!(prefix.different, vec![String::new()]);
+
+        let longer = mismatches(&actual, &json!([{}, {}, {}]), ArrayMatching::Exact);
+        assert_eq!(longer.missing, vec!["/2".to_string()]);
+    }
+
+    #[test]
+    fn it_should_match_array_prefixes() {
+        let actual = json!([1, 2, 3]);
+
+        assert!(mismatches(&actual, &json!([1, 2]), ArrayMatching::Prefix).is_empty());
+        assert_eq!(
+            mismatches(&actual, &json!([2]), ArrayMatching::Prefix).different,
+            vec!["/0".to_string()]
+        );
+    }
+
+    #[test]
+    fn it_should_match_arrays_in_any_order() {
+        let actual = json!([{ "id": 1, "role": "admin" }, { "id": 1 }, 3]);
+
+        let any_order = json!([3, { "id": 1 }, { "role": "admin" }]);
+        assert!(mismatches(&actual, &any_order, ArrayMatching::AnyOrder).is_empty());
+
+        let too_many = json!([{ "id": 1 }, { "id": 1 }, { "id": 1 }]);
+        assert_eq!(
+            mismatches(&actual, &too_many, ArrayMatching::AnyOrder).missing,
+            vec!["/2".to_string()]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    assertion::{
+        traits::{Container, Equality, Matching},
+        Assertion, Hand,
+    },
+    dsl::{Part, Predicate},
+};
+use regex::Regex;
+
+use super::compile_regex;
+
+impl Equality<str> for String {
+    type Assertion = Assertion<String>;
+
+    fn is_eq(&self, rhs: &str) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::Is,
+            part: Part::TextBody,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(rhs.to_string()),
+            result: (self == rhs).into(),
+        }
+    }
+
+    fn is_ne(&self, rhs: &str) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::IsNot,
+            part: Part::TextBody,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(rhs.to_string()),
+            result: (self != rhs).into(),
+        }
+    }
+}
+
+impl Container<str> for String {
+    type Assertion = Assertion<String>;
+
+    fn has(&self, rhs: &str) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::Contains,
+            part: Part::TextBody,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(rhs.to_string()),
+            result: self.contains(rhs).into(),
+        }
+    }
+
+    fn has_not(&self, rhs: &str) -> Self::Assertion {
+        Assertion {
+            predicate: Predicate::DoesNotContain,
+            part: Part::TextBody,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(rhs.to_string()),
+            result: (!self.contains(rhs)).into(),
+        }
+    }
+}
+
+impl Matching<str> for String {
+    type Assertion = Assertion<String>;
+
+    fn is_match(&self, re: &str) -> Self::Assertion {
+        let regex: Regex = match compile_regex(re, Predicate::Matches, Part::TextBody) {
+            Ok(regex) => regex,
+            Err(assertion) => return assertion,
+        };
+
+        Assertion {
+            predicate: Predicate::Matches,
+            part: Part::TextBody,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(re.to_string()),
+            result: regex.is_match(self).into(),
+        }
+    }
+
+    fn is_not_match(&self, re: &str) -> Self::Assertion {
+        let regex: Regex = match compile_regex(re, Predicate::DoesNotMatch, Part::TextBody) {
+            Ok(regex) => regex,
+            Err(assertion) => return assertion,
+        };
+
+        Assertion {
+            predicate: Predicate::DoesNotMatch,
+            part: Part::TextBody,
+            left: Hand::Left(self.clone()),
+            right: Hand::Right(re.to_string()),
+            result: (!regex.is_match(self)).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertion::traits::{Container, Equality, Matching};
+
+    fn text_stub() -> String {
+        "<html><body><h1>Hello, Isaac</h1></body></html>".to_string()
+    }
+
+    #[test]
+    fn impl_is_eq_str() {
+        let assertion = text_stub().is_eq("<html><body><h1>Hello, Isaac</h1></body></html>");
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_is_ne_str() {
+        let assertion = text_stub().is_ne("<html></html>");
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_has_str() {
+        let assertion = text_stub().has("Hello, Isaac");
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_has_not_str() {
+        let assertion = text_stub().has_not("Hello, Rayne");
+        assert!(assertion.passed(), "{}", assertion.log());
+
+        let assertion = text_stub().has_not("Isaac");
+        assert!(assertion.failed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_matches() {
+        let assertion = text_stub().is_match(r"<h1>Hello, \w+</h1>");
+        assert!(assertion.passed(), "{}", assertion.log());
+
+        let assertion = text_stub().is_not_match(r"<h2>");
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn impl_matches_invalid_regex() {
+        let assertion = text_stub().is_match(r"\");
+        assert!(assertion.failed(), "{}", assertion.log());
+        assert_eq!(assertion.log(), r"Invalid regex pattern: \");
+    }
+
+    mod serialization {
+        use super::*;
+        use serde_json::json;
+
+        #[test]
+        fn it_serializes_text_body_should_contain() {
+            let expected_json = json!({
+                "part": "text body",
+                "predicate": "should contain",
+                "left": text_stub(),
+                "right": "Isaac",
+                "result": "passed"
+            });
+
+            let assertion = text_stub().has("Isaac");
+
+            assert_eq!(
+                json!(assertion),
+                expected_json,
+                "Serialized assertion is not equals to the expected json",
+            );
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Comparison of the json numbers within a tolerance.
+use crate::{
+    assertion::{diff::JsonDiff, Assertion, AssertionResult, Hand},
+    dsl::Predicate,
+};
+use serde_json::Value;
+
+impl Assertion<Value> {
+    /// Evaluates again an evaluated json equality test, two numbers of the
+    /// hands being equal when their absolute difference is within the
+    /// tolerance.
+    ///
+    /// The expected numbers within the tolerance are replaced by the actual
+    /// ones, so only the numbers out of the tolerance remain in the diff.
+    /// When the arrays are `unordered`, each expected item is resolved
+    /// against the actual item it is paired with, whatever its index.
+    pub(crate) fn within_tolerance(self, epsilon: Option<f64>, unordered: bool) -> Self {
+        let Some(epsilon) = epsilon else {
+            return self;
+        };
+        if !matches!(self.predicate, Predicate::Is | Predicate::IsNot) {
+            return self;
+        }
+        if let AssertionResult::Unprocessable(_) = self.result {
+            return self;
+        }
+
+        let (right, result) = match (&self.left, &self.right) {
+            (Hand::Left(actual) | Hand::Compound(_, actual), Hand::Right(expected)) => {
+                let expected = resolve(actual, expected, epsilon, unordered);
+                let result = match self.predicate {
+                    Predicate::IsNot => actual != &expected,
+                    _ => actual == &expected,
+                };
+                (Hand::Right(expected), result)
+            }
+            _ => return self,
+        };
+
+        Assertion {
+            right,
+            result: result.into(),
+            ..self
+        }
+    }
+}
+
+/// Returns `true` if both numbers are within the tolerance.
+fn approx_eq(actual: &serde_json::Number, expected: &serde_json::Number, epsilon: f64) -> bool {
+    match (actual.as_f64(), expected.as_f64()) {
+        (Some(actual), Some(expected)) => (actual - expected).abs() <= epsilon,
+        _ => false,
+    }
+}
+
+/// Returns the expected value where the numbers within the tolerance of the
+/// actual numbers are replaced by the actual ones, so both values can be
+/// compared for equality. Arrays and objects are resolved recursively.
+///
+/// Arrays are resolved index by index, or, when `unordered`, each expected
+/// item is resolved against the actual item it is paired with.
+pub(super) fn resolve(actual: &Value, expected: &Value, epsilon: f64, unordered: bool) -> Value {
+    match (actual, expected) {
+        (Value::Number(actual_number), Value::Number(expected_number))
+            if approx_eq(actual_number, expected_number, epsilon) =>
+        {
+            actual.clone()
+        }
+        (Value::Object(actual), Value::Object(expected)) => Value::Object(
+            expected
+                .iter()
+                .map(|(key, expected)| {
+                    let value = match actual.get(key) {
+                        Some(actual) => resolve(actual, expected, epsilon, unordered),
+                        None => expected.clone(),
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        (Value::Array(actual), Value::Array(expected)) if unordered => {
+            let pairs = pair(actual, expected, epsilon);
+            Value::Array(
+                expected
+                    .iter()
+                    .zip(pairs)
+                    .map(|(expected, pair)| match pair {
+                        Some(index) => resolve(&actual[index], expected, epsilon, unordered),
+                        None => expected.clone(),
+                    })
+                    .collect(),
+            )
+        }
+        (Value::Array(actual), Value::Array(expected)) => Value::Array(
+            expected
+                .iter()
+                .enumerate()
+                .map(|(index, expected)| match actual.get(index) {
+                    Some(actual) => resolve(actual, expected, epsilon, unordered),
+                    None => expected.clone(),
+                })
+                .collect(),
+        ),
+        (_, expected) => expected.clone(),
+    }
+}
+
+/// Pairs the expected items with the actual items they are equal to within
+/// the tolerance, in any order, and returns the index of the actual item
+/// paired with each expected item.
+///
+/// Unlike equal items, items within the tolerance are not interchangeable:
+/// `1.0` and `1.15` both fit `1.1` with a tolerance of `0.1` but only the
+/// former fits `1.0`. A maximum matching is searched so a greedy choice
+/// never leaves an item unpaired while a pairing of all the items exists.
+fn pair(actual: &[Value], expected: &[Value], epsilon: f64) -> Vec<Option<usize>> {
+    let fits: Vec<Vec<bool>> = expected
+        .iter()
+        .map(|expected| {
+            actual
+                .iter()
+                .map(|actual| {
+                    let resolved = resolve(actual, expected, epsilon, true);
+                    JsonDiff::unordered(&resolved, actual).is_empty()
+                })
+                .collect()
+        })
+        .collect();
+
+    // The expected item paired with each actual item.
+    let mut owners = vec![None; actual.len()];
+    for index in 0..expected.len() {
+        let mut visited = vec![false; actual.len()];
+        augment(index, &fits, &mut visited, &mut owners);
+    }
+
+    let mut pairs = vec![None; expected.len()];
+    for (actual_index, owner) in owners.into_iter().enumerate() {
+        if let Some(expected_index) = owner {
+            pairs[expected_index] = Some(actual_index);
+        }
+    }
+
+    pairs
+}
+
+/// Tries to pair the expected item at `index`, taking an actual item from
+/// another expected item when that one can be paired again elsewhere.
+fn augment(
+    index: usize,
+    fits: &[Vec<bool>],
+    visited: &mut [bool],
+    owners: &mut [Option<usize>],
+) -> bool {
+    for actual_index in 0..owners.len() {
+        if !fits[index][actual_index] || visited[actual_index] {
+            continue;
+        }
+        visited[actual_index] = true;
+        let free = match owners[actual_index] {
+            Some(owner) => augment(owner, fits, visited, owners),
+            None => true,
+        };
+        if free {
+            owners[actual_index] = Some(index);
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve;
+    use crate::assertion::{Assertion, Hand};
+    use crate::dsl::{Part, Predicate};
+    use serde_json::{json, Value};
+
+    fn json_body_assertion(actual: Value, expected: Value) -> Assertion<Value> {
+        let result = actual == expected;
+        Assertion {
+            part: Part::JsonBody,
+            predicate: Predicate::Is,
+            left: Hand::Left(actual),
+            right: Hand::Right(expected),
+            result: result.into(),
+        }
+    }
+
+    #[test]
+    fn it_should_compare_nested_numbers_within_tolerance() {
+        let actual = json!({ "total": 0.1 + 0.2, "items": [{ "price": 1.0 }], "count": 2 });
+        let expected = json!({ "total": 0.3, "items": [{ "price": 1 }], "count": 2 });
+
+        let assertion = json_body_assertion(actual, expected).within_tolerance(Some(1e-9), false);
+
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn it_should_keep_numbers_out_of_tolerance() {
+        let actual = json!({ "lat": 48.8566, "lng": 2.3522 });
+        let expected = json!({ "lat": 48.8567, "lng": 2.4 });
+
+        let assertion = json_body_assertion(actual, expected).within_tolerance(Some(1e-3), false);
+
+        assert!(assertion.failed());
+        let diff = assertion.diff().expect("A json diff");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].pointer, "/lng");
+    }
+
+    #[test]
+    fn it_should_not_resolve_other_types() {
+        let resolved = resolve(&json!(["1", true]), &json!([1, true]), 1.0, false);
+
+        assert_eq!(resolved, json!([1, true]));
+    }
+
+    #[test]
+    fn it_should_pair_numbers_within_tolerance_in_any_order() {
+        let actual = json!([2.05, 0.98]);
+        let expected = json!([1.0, 2.0]);
+
+        let assertion = json_body_assertion(actual, expected)
+            .within_tolerance(Some(0.1), true)
+            .in_any_order(true);
+
+        assert!(assertion.passed(), "{}", assertion.log());
+    }
+
+    #[test]
+    fn it_should_not_pair_numbers_greedily() {
+        let resolved = resolve(&json!([1.1, 1.0]), &json!([1.0, 1.15]), 0.1, true);
+
+        assert_eq!(resolved, json!([1.0, 1.1]));
+    }
+
+    #[test]
+    fn it_should_keep_unpaired_items_in_the_diff() {
+        let actual = json!([{ "id": 2, "price": 9.99 }, { "id": 1, "price": 1.5 }]);
+        let expected = json!([{ "id": 1, "price": 1.49 }, { "id": 2, "price": 8.0 }]);
+
+        let assertion = json_body_assertion(actual, expected)
+            .within_tolerance(Some(0.05), true)
+            .in_any_order(true);
+
+        assert!(assertion.failed());
+        let diff = assertion.diff().expect("A json diff");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].pointer, "/1");
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].pointer, "/0");
+    }
+}
@@ -0,0 +1,94 @@
+//! Comparison of the json arrays in any order.
+use crate::{
+    assertion::{diff::JsonDiff, Assertion, AssertionResult, Hand},
+    dsl::Predicate,
+};
+use serde_json::Value;
+
+impl Assertion<Value> {
+    /// Evaluates again an evaluated json equality test, the arrays of the
+    /// hands being compared as multisets.
+    ///
+    /// The predicate of an equality test becomes [`Predicate::IsUnordered`],
+    /// so the diff of a failure reports the array items missing from either
+    /// hand instead of the differences index by index.
+    pub(crate) fn in_any_order(self, unordered: bool) -> Self {
+        if !unordered || !matches!(self.predicate, Predicate::Is | Predicate::IsNot) {
+            return self;
+        }
+        if let AssertionResult::Unprocessable(_) = self.result {
+            return self;
+        }
+
+        let equal = match (&self.left, &self.right) {
+            (Hand::Left(actual) | Hand::Compound(_, actual), Hand::Right(expected)) => {
+                JsonDiff::unordered(expected, actual).is_empty()
+            }
+            _ => return self,
+        };
+        let (predicate, result) = match self.predicate {
+            Predicate::IsNot => (Predicate::IsNot, !equal),
+            _ => (Predicate::IsUnordered, equal),
+        };
+
+        Assertion {
+            predicate,
+            result: result.into(),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertion::{Assertion, Hand};
+    use crate::dsl::{Part, Predicate};
+    use serde_json::{json, Value};
+
+    fn json_body_assertion(
+        predicate: Predicate,
+        actual: Value,
+        expected: Value,
+    ) -> Assertion<Value> {
+        let result = match predicate {
+            Predicate::IsNot => actual != expected,
+            _ => actual == expected,
+        };
+        Assertion {
+            part: Part::JsonBody,
+            predicate,
+            left: Hand::Left(actual),
+            right: Hand::Right(expected),
+            result: result.into(),
+        }
+    }
+
+    #[test]
+    fn it_should_compare_arrays_in_any_order() {
+        let actual = json!({ "users": [{ "id": 2, "roles": ["b", "a"] }, { "id": 1 }] });
+        let expected = json!({ "users": [{ "id": 1 }, { "id": 2, "roles": ["a", "b"] }] });
+
+        let assertion =
+            json_body_assertion(Predicate::Is, actual.clone(), expected.clone()).in_any_order(true);
+        assert!(assertion.passed(), "{}", assertion.log());
+        assert_eq!(assertion.predicate, Predicate::IsUnordered);
+
+        let assertion = json_body_assertion(Predicate::IsNot, actual, expected).in_any_order(true);
+        assert!(assertion.failed());
+    }
+
+    #[test]
+    fn it_should_report_the_missing_items() {
+        let actual = json!([1, 2, 2]);
+        let expected = json!([2, 1, 3]);
+
+        let assertion = json_body_assertion(Predicate::Is, actual, expected).in_any_order(true);
+
+        assert!(assertion.failed());
+        assert!(
+            assertion.log().ends_with("diff:\n- /2: 3\n+ /2: 2"),
+            "{}",
+            assertion.log()
+        );
+    }
+}
@@ -14,18 +14,24 @@
 //!
 //! [`dsl`]: crate::dsl
 
+pub mod diff;
 mod impls;
 #[allow(clippy::wrong_self_convention)]
 pub mod traits;
 
 use crate::{
+    assertion::diff::JsonDiff,
     dsl::{Part, Predicate},
     grillon::LogSettings,
+    report::RequestInfo,
 };
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 use serde_json::{json, Value};
 use std::any::Any;
+use std::cell::RefCell;
 use std::fmt::Debug;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
 use strum::Display;
 
 /// Short-hand types and aliases used for assertions.
@@ -44,6 +50,9 @@ pub mod types {
     /// An alias to manipulate an internal representation of a header as a
     /// `String`.
     pub type Header = String;
+    /// An alias to manipulate an internal representation of the values of a
+    /// header as strings.
+    pub type HeaderValues = Vec<String>;
 }
 
 /// Represents left or right hands in an [`Assertion`].
@@ -94,32 +103,63 @@ where
 /// fails not because the word is missing, but
 /// because the file content cannot be
 /// processed.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum UnprocessableReason {
     /// Unprocessable json path with the string representation of the path.
     InvalidJsonPath(String),
     /// Unprocessable json body because it's missing.
     MissingJsonBody,
+    /// Unprocessable json body because it cannot be decoded. Contains the
+    /// decoding error and a truncated snippet of the body.
+    InvalidJsonBody(String),
     /// Unprocessable header value because the correspond header key is missing.
     MissingHeader,
+    /// Unprocessable cookie because no `Set-Cookie` header sets it. Contains
+    /// the name of the cookie.
+    MissingCookie(String),
     /// Unprocessable json schema.
     InvalidJsonSchema(String, String),
     /// Serialization failure.
     SerializationFailure(String),
     /// Invalid HTTP request headers.
     InvalidHttpRequestHeaders(String),
+    /// Invalid HTTP request url.
+    InvalidHttpRequestUrl(String),
+    /// Invalid HTTP request body.
+    InvalidHttpRequestBody(String),
     /// Invalid HTTP header value.
     InvalidHeaderValue(String),
     /// Invalid regex pattern.
     InvalidRegex(String),
+    /// A value compared with a number isn't a number. Contains the
+    /// description of the offending value.
+    NotANumber(String),
+    /// The length of a value cannot be measured. Contains the description of
+    /// the offending value.
+    UnmeasurableLength(String),
+    /// A value cannot be captured from the response.
+    CaptureFailure(String),
     /// If the HTTP request results in an error while sending request, redirect
     /// loop was detected or redirect limit was exhausted.
     HttpRequestFailure(String),
+    /// The HTTP request didn't complete before its timeout. Contains the time
+    /// elapsed since the request was sent, serialized in milliseconds.
+    Timeout {
+        /// The time elapsed since the request was sent.
+        #[serde(serialize_with = "serialize_millis")]
+        elapsed: Duration,
+    },
     /// Unprocessable entity.
     Other(String),
 }
 
+/// Serializes a [`Duration`] as a number of milliseconds.
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    // See the response time for the cast from u128 to u64.
+    serializer.serialize_u64(duration.as_millis() as u64)
+}
+
 // Strum cannot be used here since sum type fields are
 // not supported yet just like positional arguments for
 // tuple variants.
@@ -132,9 +172,15 @@ impl std::fmt::Display for UnprocessableReason {
             UnprocessableReason::MissingJsonBody => {
                 write!(f, "Unprocessable json body: missing")
             }
+            UnprocessableReason::InvalidJsonBody(details) => {
+                write!(f, "Invalid json body: {details}")
+            }
             UnprocessableReason::MissingHeader => {
                 write!(f, "Unprocessable header: header key is missing")
             }
+            UnprocessableReason::MissingCookie(name) => {
+                write!(f, "Unprocessable cookie: no Set-Cookie header sets {name}")
+            }
             UnprocessableReason::InvalidJsonSchema(schema, instance) => {
                 write!(f, "Invalid json schema: {schema} => {instance}")
             }
@@ -144,22 +190,40 @@ impl std::fmt::Display for UnprocessableReason {
             UnprocessableReason::InvalidHttpRequestHeaders(details) => {
                 write!(f, "Invalid HTTP request headers: {details}")
             }
+            UnprocessableReason::InvalidHttpRequestUrl(details) => {
+                write!(f, "Invalid HTTP request url: {details}")
+            }
+            UnprocessableReason::InvalidHttpRequestBody(details) => {
+                write!(f, "Invalid HTTP request body: {details}")
+            }
             UnprocessableReason::InvalidHeaderValue(details) => {
                 write!(f, "Invalid HTTP response header value: {details}")
             }
             UnprocessableReason::InvalidRegex(regex) => {
                 write!(f, "Invalid regex pattern: {regex}")
             }
+            UnprocessableReason::NotANumber(details) => {
+                write!(f, "Expected a number: {details}")
+            }
+            UnprocessableReason::UnmeasurableLength(details) => {
+                write!(f, "Unprocessable length: {details}")
+            }
+            UnprocessableReason::CaptureFailure(details) => {
+                write!(f, "Capture failure: {details}")
+            }
             UnprocessableReason::HttpRequestFailure(details) => {
                 write!(f, "Http request failure: {details}")
             }
+            UnprocessableReason::Timeout { elapsed } => {
+                write!(f, "Http request timeout after {} ms", elapsed.as_millis())
+            }
             UnprocessableReason::Other(message) => write!(f, "{message}"),
         }
     }
 }
 
 /// The assertion's result.
-#[derive(Serialize, Display, Debug)]
+#[derive(Serialize, Display, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum AssertionResult {
@@ -189,33 +253,99 @@ impl AssertionLog {
     /// Builds the assertion message based on the [`Predicate`], the [`Part`]
     /// and the [`AssertionResult`].
     pub fn new<T: Any + Debug + Serialize>(assertion: &Assertion<T>) -> Self {
+        Self::build(assertion, false)
+    }
+
+    /// Builds the assertion message, the json diff is colored with ANSI codes
+    /// when `colored` is `true`.
+    fn build<T: Any + Debug + Serialize>(assertion: &Assertion<T>, colored: bool) -> Self {
         if let AssertionResult::Unprocessable(reason) = &assertion.result {
             return Self(format!("{reason}"));
         }
 
-        match assertion.part {
-            Part::JsonPath => Self::jsonpath_log(assertion),
+        let log = match (&assertion.part, &assertion.predicate) {
+            (Part::JsonPath, _) => Self::jsonpath_log(assertion),
+            (Part::JsonBody, Predicate::Includes) => Self::includes_log(assertion),
             _ => Self::log(assertion),
+        };
+
+        match assertion.diff() {
+            Some(diff) => Self(format!("{}\ndiff:\n{}", log.0, diff.render(colored))),
+            None => log,
         }
     }
 
+    /// Builds the message of an inclusion test. The left hand is composed of
+    /// the actual value and the mismatches, only the mismatches are logged
+    /// since the actual value can be large.
+    fn includes_log<T: Any + Debug + Serialize>(assertion: &Assertion<T>) -> Self {
+        let predicate = &assertion.predicate;
+        let part = &assertion.part;
+
+        let mismatches = match &assertion.left {
+            Hand::Compound(_, mismatches) => mismatches,
+            _ => return Self("<unexpected left hand>".to_string()),
+        };
+        let right = match &assertion.right {
+            Hand::Right(right) => right,
+            _ => return Self("<unexpected right hand>".to_string()),
+        };
+
+        let message = match &assertion.result {
+            AssertionResult::Failed => {
+                let mut message = format!(
+                    "result: failed
+part: {part}
+{predicate}: {right:#?}"
+                );
+                #[allow(trivial_casts)]
+                if let Some(mismatches) = (mismatches as &dyn Any).downcast_ref::<Value>() {
+                    for kind in ["missing", "different"] {
+                        let Some(Value::Array(pointers)) = mismatches.get(kind) else {
+                            continue;
+                        };
+                        if pointers.is_empty() {
+                            continue;
+                        }
+                        let pointers: Vec<String> =
+                            pointers.iter().map(|pointer| pointer.to_string()).collect();
+                        message.push_str(&format!("\n{kind}: {}", pointers.join(", ")));
+                    }
+                }
+
+                message
+            }
+            result => format!(
+                "result: {result}
+part: {part}
+{predicate}: {right:#?}"
+            ),
+        };
+
+        Self(message)
+    }
+
     fn log<T: Debug + Serialize>(assertion: &Assertion<T>) -> Self {
         let predicate = &assertion.predicate;
         let part = &assertion.part;
 
+        // An empty left hand is an absent value.
         let left = match &assertion.left {
             Hand::Left(left) => format!("{left:#?}"),
             Hand::Compound(left, right) if part == &Part::StatusCode => {
                 format!("{left:#?} and {right:#?}")
             }
+            Hand::Empty => "none".to_string(),
             _ => "Unexpected left hand in right hand".to_string(),
         };
-        let right = match &assertion.right {
-            Hand::Right(right) => format!("{right:#?}"),
+        // Predicates such as existence checks don't expect any value.
+        let expected = match &assertion.right {
+            Hand::Right(right) => format!("{predicate}: {right:#?}"),
             Hand::Compound(left, right) if part == &Part::StatusCode => {
-                format!("{left:#?} and {right:#?}")
+                format!("{predicate}: {left:#?} and {right:#?}")
             }
-            _ => "Unexpected left hand in right hand".to_string(),
+            Hand::Empty => predicate.to_string(),
+            _ => format!("{predicate}: Unexpected left hand in right hand"),
         };
 
         let result = &assertion.result;
@@ -224,12 +354,12 @@ impl AssertionLog {
             AssertionResult::Passed => format!(
                 "result: {result}
 {part}
-{predicate}: {right}"
+{expected}"
             ),
             AssertionResult::Failed => format!(
                 "result: {result}
 {part}
-{predicate}: {right}
+{expected}
 was: {left}"
             ),
             AssertionResult::NotYetStarted => format!("Not yet started : {part}"),
@@ -247,8 +377,10 @@ was: {left}"
             Hand::Compound(left, right) if part == &Part::JsonPath => (left, right),
             _ => return Self("<unexpected left hand>".to_string()),
         };
-        let right_hand = match &assertion.right {
-            Hand::Right(right) if part == &Part::JsonPath => right,
+        // Predicates such as existence checks don't expect any value.
+        let expected = match &assertion.right {
+            Hand::Right(right) if part == &Part::JsonPath => format!("{predicate}: {right:#?}"),
+            Hand::Empty => predicate.to_string(),
             _ => return Self("<unexpected right hand>".to_string()),
         };
 
@@ -267,12 +399,12 @@ was: {left}"
             AssertionResult::Passed => format!(
                 "result: {result}
 {part}
-{predicate}: {right_hand:#?}"
+{expected}"
             ),
             AssertionResult::Failed => format!(
                 "result: {result}
 {part}
-{predicate}: {right_hand:#?}
+{expected}
 was: {jsonpath_value:#?}"
             ),
             AssertionResult::NotYetStarted => format!("[Not yet started] {part}"),
@@ -302,24 +434,152 @@ where
 
     /// Runs the assertion and produce the the result results with the given
     /// [`LogSettings`].
+    ///
+    /// With [`LogSettings::JsonOutput`], the json line holds the request of
+    /// the response when the assertion is evaluated by an [`Assert`] created
+    /// from a [`Request`], see [`Assertion::assert_with_request`] to set it
+    /// otherwise.
+    ///
+    /// [`Assert`]: crate::Assert
+    /// [`Request`]: crate::Request
     pub fn assert(self, log_settings: &LogSettings) -> Assertion<T> {
-        let message = self.log();
+        let request = CURRENT_REQUEST.with_borrow(Clone::clone);
+
+        self.assert_with_request(log_settings, request.as_ref())
+    }
+
+    /// Runs the assertion like [`Assertion::assert`], the json line written
+    /// with [`LogSettings::JsonOutput`] holding the given request.
+    pub fn assert_with_request(
+        self,
+        log_settings: &LogSettings,
+        request: Option<&RequestInfo>,
+    ) -> Assertion<T> {
+        // The panic message of a failed assertion is written to stderr.
+        let colored = match log_settings {
+            LogSettings::StdAssert => colors_enabled(io::stderr().is_terminal()),
+            _ => colors_enabled(io::stdout().is_terminal()),
+        };
+        let message = AssertionLog::build(&self, colored).0;
         match log_settings {
             LogSettings::StdOutput => println!("\n{message}"),
             LogSettings::StdAssert => assert!(self.passed(), "\n\n{message}"),
-            LogSettings::JsonOutput => {
-                let json = serde_json::to_string(&json!(self))
-                    .expect("Unexpected json failure: failed to serialize assertion");
-                println!("{json}");
+            LogSettings::SoftAssert => {
+                if !self.passed() {
+                    println!("\n{message}");
+                }
             }
+            LogSettings::JsonOutput => println!("{}", self.to_json(request)),
         }
 
         self
     }
 
-    fn log(&self) -> String {
+    /// Returns the json representation of the assertion, with the diff of a
+    /// failed json equality test and the request of the response when known.
+    fn to_json(&self, request: Option<&RequestInfo>) -> Value {
+        let mut json = json!(self);
+        if let Some(diff) = self.diff() {
+            json["diff"] = json!(diff);
+        }
+        if let Some(request) = request {
+            json["request"] = json!(request);
+        }
+
+        json
+    }
+
+    pub(crate) fn log(&self) -> String {
         AssertionLog::new(self).0
     }
+
+    /// Returns the differences between the expected and the actual json
+    /// values of a failed equality test on the json body or a json path, or
+    /// of a failed headers snapshot test. The
+    /// arrays are compared as multisets for [`Predicate::IsUnordered`].
+    ///
+    /// A json path value is wrapped in an array by the query, a single found
+    /// value is unwrapped to locate the differences from the value itself.
+    pub fn diff(&self) -> Option<JsonDiff> {
+        if !matches!(self.result, AssertionResult::Failed)
+            || !matches!(
+                self.predicate,
+                Predicate::Is
+                    | Predicate::IsApprox
+                    | Predicate::IsUnordered
+                    | Predicate::MatchesSnapshot
+            )
+        {
+            return None;
+        }
+
+        let (actual, expected) = match (&self.part, &self.left, &self.right) {
+            (Part::JsonBody, Hand::Left(actual), Hand::Right(expected)) => (actual, expected),
+            (Part::Headers, Hand::Left(actual), Hand::Right(expected))
+                if self.predicate == Predicate::MatchesSnapshot =>
+            {
+                (actual, expected)
+            }
+            (Part::JsonPath, Hand::Compound(_, actual), Hand::Right(expected)) => {
+                (actual, expected)
+            }
+            _ => return None,
+        };
+
+        #[allow(trivial_casts)]
+        let (Some(actual), Some(expected)) = (
+            (actual as &dyn Any).downcast_ref::<Value>(),
+            (expected as &dyn Any).downcast_ref::<Value>(),
+        ) else {
+            return None;
+        };
+
+        let compute = match self.predicate {
+            Predicate::IsUnordered => JsonDiff::unordered,
+            _ => JsonDiff::new,
+        };
+        let diff = match (actual, expected) {
+            (Value::Array(actual), Value::Array(expected))
+                if self.part == Part::JsonPath && actual.len() == 1 && expected.len() == 1 =>
+            {
+                compute(&expected[0], &actual[0])
+            }
+            _ => compute(expected, actual),
+        };
+
+        (!diff.is_empty()).then_some(diff)
+    }
+}
+
+/// Returns `true` if the log written to a destination is colored: the
+/// destination is a terminal and the `NO_COLOR` environment variable is unset,
+/// see <https://no-color.org>.
+fn colors_enabled(is_terminal: bool) -> bool {
+    is_terminal && std::env::var_os("NO_COLOR").is_none()
+}
+
+thread_local! {
+    /// The request whose response is asserted, set while an [`Assert`]
+    /// evaluates an assertion.
+    ///
+    /// [`Assert`]: crate::Assert
+    static CURRENT_REQUEST: RefCell<Option<RequestInfo>> = const { RefCell::new(None) };
+}
+
+/// Evaluates `f`, the assertions run by [`Assertion::assert`] in it writing
+/// the given request in their json line.
+pub(crate) fn with_request<R>(request: Option<&RequestInfo>, f: impl FnOnce() -> R) -> R {
+    // Restores the previous request, also when a failed assertion panics.
+    struct Restore(Option<RequestInfo>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_REQUEST.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT_REQUEST.replace(request.cloned()));
+
+    f()
 }
 
 impl From<bool> for AssertionResult {
@@ -334,10 +594,39 @@ impl From<bool> for AssertionResult {
 
 #[cfg(test)]
 mod tests {
-    use super::{AssertionResult, Hand};
-    use crate::dsl::Predicate::{Between, LessThan};
-    use crate::{assertion::Assertion, dsl::Part};
-    use serde_json::json;
+    use super::{
+        colors_enabled, with_request, AssertionLog, AssertionResult, Hand, UnprocessableReason,
+        CURRENT_REQUEST,
+    };
+    use crate::dsl::Predicate::{self, Between, LessThan, NoPredicate};
+    use crate::{assertion::Assertion, dsl::Part, report::RequestInfo};
+    use serde_json::{json, Value};
+    use std::time::Duration;
+
+    #[test]
+    fn it_should_write_the_request_in_the_json_log() {
+        let assertion: Assertion<u16> = Assertion {
+            part: Part::StatusCode,
+            predicate: Predicate::Is,
+            left: Hand::Left(200),
+            right: Hand::Right(200),
+            result: AssertionResult::Passed,
+        };
+        let request = RequestInfo {
+            method: "GET".to_string(),
+            url: "http://localhost:8080/users".to_string(),
+        };
+
+        assert_eq!(
+            assertion.to_json(Some(&request))["request"],
+            json!({ "method": "GET", "url": "http://localhost:8080/users" })
+        );
+        assert!(assertion.to_json(None).get("request").is_none());
+
+        let current = with_request(Some(&request), || CURRENT_REQUEST.with_borrow(Clone::clone));
+        assert_eq!(current, Some(request));
+        assert_eq!(CURRENT_REQUEST.with_borrow(Clone::clone), None);
+    }
 
     #[test]
     fn it_should_serialize_status_code() {
@@ -380,4 +669,77 @@ mod tests {
 
         assert_eq!(json!(assertion), expected_json);
     }
+
+    #[test]
+    fn it_should_serialize_timeout() {
+        let assertion: Assertion<Value> = Assertion {
+            part: Part::NoPart,
+            predicate: NoPredicate,
+            left: Hand::Empty,
+            right: Hand::Empty,
+            result: AssertionResult::Unprocessable(UnprocessableReason::Timeout {
+                elapsed: Duration::from_millis(1500),
+            }),
+        };
+
+        let expected_json = json!({
+            "part": "none",
+            "predicate": "none",
+            "left": null,
+            "right": null,
+            "result": { "unprocessable": { "timeout": { "elapsed": 1500 } } }
+        });
+
+        assert_eq!(json!(assertion), expected_json);
+        assert_eq!(assertion.log(), "Http request timeout after 1500 ms");
+    }
+
+    #[test]
+    fn it_should_diff_failed_json_equality() {
+        let assertion: Assertion<Value> = Assertion {
+            part: Part::JsonPath,
+            predicate: Predicate::Is,
+            left: Hand::Compound(json!("$.user"), json!([{ "id": 1, "name": "Isaac" }])),
+            right: Hand::Right(json!([{ "id": 1, "name": "Max" }])),
+            result: AssertionResult::Failed,
+        };
+
+        let diff = assertion.diff().expect("A json diff");
+        assert_eq!(diff.changed[0].pointer, "/name");
+        assert!(
+            assertion
+                .log()
+                .ends_with("diff:\n~ /name: \"Max\" -> \"Isaac\""),
+            "{}",
+            assertion.log()
+        );
+    }
+
+    #[test]
+    fn it_should_not_color_logs_out_of_a_terminal() {
+        let assertion: Assertion<Value> = Assertion {
+            part: Part::JsonBody,
+            predicate: Predicate::Is,
+            left: Hand::Left(json!({ "id": 1 })),
+            right: Hand::Right(json!({ "id": 2 })),
+            result: AssertionResult::Failed,
+        };
+
+        let message = AssertionLog::build(&assertion, colors_enabled(false)).0;
+        assert!(message.ends_with("diff:\n~ /id: 2 -> 1"), "{message}");
+        assert!(!json!(assertion.diff()).to_string().contains('\u{1b}'));
+    }
+
+    #[test]
+    fn it_should_not_diff_passed_assertions() {
+        let assertion: Assertion<Value> = Assertion {
+            part: Part::JsonBody,
+            predicate: Predicate::Is,
+            left: Hand::Left(json!({ "id": 1 })),
+            right: Hand::Right(json!({ "id": 1 })),
+            result: AssertionResult::Passed,
+        };
+
+        assert!(assertion.diff().is_none());
+    }
 }
@@ -13,6 +13,27 @@ pub trait Equality<Rhs: ?Sized = Self> {
     fn is_ne(&self, rhs: &Rhs) -> Self::Assertion;
 }
 
+/// Trait to test the equality between two values within a tolerance.
+pub trait ApproxEquality<Rhs: ?Sized = Self> {
+    /// The resulting assertion after applying the approximate equality test.
+    type Assertion;
+
+    /// Asserts the equality, two numbers are equal when their absolute
+    /// difference is less than or equal to `epsilon`.
+    fn approx_eq(&self, rhs: &Rhs, epsilon: f64) -> Self::Assertion;
+}
+
+/// Trait to test the equality between two values, the arrays being compared
+/// in any order.
+pub trait UnorderedEquality<Rhs: ?Sized = Self> {
+    /// The resulting assertion after applying the unordered equality test.
+    type Assertion;
+
+    /// Asserts the equality, two arrays are equal when they have the same
+    /// items in any order.
+    fn unordered_eq(&self, rhs: &Rhs) -> Self::Assertion;
+}
+
 /// Trait to test if a value is withing an inclusive range.
 pub trait RangeInclusive<T: ?Sized> {
     /// The resulting assertion after applying the inclusive range test.
@@ -31,6 +52,67 @@ pub trait LessThan<T: ?Sized> {
     fn less_than(&self, other: &T) -> Self::Assertion;
 }
 
+/// Trait to test if a value is less than or equal to the other.
+pub trait LessOrEqual<T: ?Sized> {
+    /// The resulting assertion after applying the less or equal test.
+    type Assertion;
+
+    /// Asserts the value is less than or equal to other.
+    fn less_or_equal(&self, other: &T) -> Self::Assertion;
+}
+
+/// Trait to test if a value is greater than the other.
+pub trait GreaterThan<T: ?Sized> {
+    /// The resulting assertion after applying the greater than test.
+    type Assertion;
+
+    /// Asserts the value is greater than other.
+    fn greater_than(&self, other: &T) -> Self::Assertion;
+}
+
+/// Trait to test if a value is greater than or equal to the other.
+pub trait GreaterOrEqual<T: ?Sized> {
+    /// The resulting assertion after applying the greater or equal test.
+    type Assertion;
+
+    /// Asserts the value is greater than or equal to other.
+    fn greater_or_equal(&self, other: &T) -> Self::Assertion;
+}
+
+/// Trait to test if a value is present.
+pub trait Existence {
+    /// The resulting assertion after applying the existence test.
+    type Assertion;
+
+    /// Asserts that the value is present.
+    fn is_present(&self) -> Self::Assertion;
+
+    /// Asserts that the value is absent.
+    fn is_absent(&self) -> Self::Assertion;
+}
+
+/// Trait to test the length of a value.
+pub trait HasLength<T: ?Sized> {
+    /// The resulting assertion after applying the length test.
+    type Assertion;
+
+    /// Asserts that the length of the value is equal to the expected one.
+    fn length_eq(&self, length: &T) -> Self::Assertion;
+
+    /// Asserts that the length of the value is within the closed interval
+    /// [min, max].
+    fn length_in_range(&self, min: &T, max: &T) -> Self::Assertion;
+}
+
+/// Trait to test the type of a value.
+pub trait OfType<T: ?Sized> {
+    /// The resulting assertion after applying the type test.
+    type Assertion;
+
+    /// Asserts that the value is of the expected type.
+    fn of_type(&self, expected: &T) -> Self::Assertion;
+}
+
 /// A representation of a container of items where we can perform inner checks
 /// with `has` and `has_not` functions.
 pub trait Container<T: ?Sized> {
@@ -44,6 +126,36 @@ pub trait Container<T: ?Sized> {
     fn has_not(&self, other: &T) -> Self::Assertion;
 }
 
+/// Trait to test the beginning and the end of a value.
+pub trait Affix<T: ?Sized> {
+    /// The resulting assertion after applying the affix test.
+    type Assertion;
+
+    /// Asserts that the value starts with the prefix.
+    fn has_prefix(&self, prefix: &T) -> Self::Assertion;
+
+    /// Asserts that the value ends with the suffix.
+    fn has_suffix(&self, suffix: &T) -> Self::Assertion;
+}
+
+/// Trait to test if a value includes a subset.
+pub trait Inclusion<T: ?Sized> {
+    /// The resulting assertion after applying the inclusion test.
+    type Assertion;
+
+    /// Asserts that the value includes the subset.
+    fn includes(&self, subset: &T) -> Self::Assertion;
+}
+
+/// Trait to test if a value matches a stored snapshot.
+pub trait SnapshotMatching<T: ?Sized> {
+    /// The resulting assertion after applying the snapshot test.
+    type Assertion;
+
+    /// Asserts that the value matches the snapshot.
+    fn matches_snapshot(&self, snapshot: &T) -> Self::Assertion;
+}
+
 /// Trait to test if a json value matches the json schema.
 pub trait JsonSchema<T: ?Sized> {
     /// The resulting assertion after applying the json schema test.
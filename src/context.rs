@@ -0,0 +1,146 @@
+//! The `context` module provides a shared store of variables captured from
+//! http responses and reused in subsequent requests.
+use regex::{Captures, Regex};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Matches `{{name}}` placeholders, spaces around the name are allowed.
+fn placeholder_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"\{\{\s*([^{}\s]+)\s*\}\}").expect("Valid placeholder regex"))
+}
+
+/// A shared store of named json values.
+///
+/// The context is owned by a [`Grillon`] instance and values are captured
+/// with [`Assert::capture`], [`Assert::capture_all`] and
+/// [`Assert::capture_header`]. The `{{name}}` placeholders in request paths,
+/// json payloads and header values are replaced by the captured values.
+/// Placeholders without a captured value are left untouched. The expected
+/// values of the assertions are never interpolated.
+///
+/// Cloning a context gives a handle to the same store.
+///
+/// [`Grillon`]: crate::Grillon
+/// [`Assert::capture`]: crate::Assert::capture
+/// [`Assert::capture_all`]: crate::Assert::capture_all
+/// [`Assert::capture_header`]: crate::Assert::capture_header
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    values: Arc<RwLock<HashMap<String, Value>>>,
+}
+
+impl Context {
+    /// Returns the value stored with the given name.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.values
+            .read()
+            .expect("Context lock poisoned")
+            .get(name)
+            .cloned()
+    }
+
+    /// Stores a value with the given name, replacing the previous one.
+    pub fn set<N: Into<String>>(&self, name: N, value: Value) {
+        self.values
+            .write()
+            .expect("Context lock poisoned")
+            .insert(name.into(), value);
+    }
+
+    /// Replaces the placeholders of the given text. A string value is
+    /// inserted as-is, other values with their json representation.
+    pub(crate) fn interpolate(&self, text: &str) -> String {
+        placeholder_regex()
+            .replace_all(text, |caps: &Captures<'_>| match self.get(&caps[1]) {
+                Some(Value::String(value)) => value,
+                Some(value) => value.to_string(),
+                None => caps[0].to_string(),
+            })
+            .into_owned()
+    }
+
+    /// Replaces the placeholders of the strings contained in the given json
+    /// value. A string made of a single placeholder is replaced by the
+    /// captured value, keeping its json type.
+    pub(crate) fn interpolate_json(&self, json: Value) -> Value {
+        match json {
+            Value::String(text) => {
+                let whole = placeholder_regex()
+                    .captures(&text)
+                    .filter(|caps| caps[0].len() == text.len())
+                    .and_then(|caps| self.get(&caps[1]));
+
+                whole.unwrap_or_else(|| Value::String(self.interpolate(&text)))
+            }
+            Value::Array(values) => Value::Array(
+                values
+                    .into_iter()
+                    .map(|value| self.interpolate_json(value))
+                    .collect(),
+            ),
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| (key, self.interpolate_json(value)))
+                    .collect(),
+            ),
+            value => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+    use serde_json::json;
+
+    fn context_stub() -> Context {
+        let context = Context::default();
+        context.set("id", json!(42));
+        context.set("name", json!("Isaac"));
+
+        context
+    }
+
+    #[test]
+    fn it_should_interpolate_text() {
+        let context = context_stub();
+
+        assert_eq!(context.interpolate("users/{{id}}"), "users/42");
+        assert_eq!(context.interpolate("{{ name }}-{{id}}"), "Isaac-42");
+        assert_eq!(
+            context.interpolate("users/{{unknown}}"),
+            "users/{{unknown}}"
+        );
+    }
+
+    #[test]
+    fn it_should_interpolate_json() {
+        let context = context_stub();
+        let json = json!({
+            "id": "{{id}}",
+            "label": "user {{id}}",
+            "friends": [{ "name": "{{name}}" }],
+            "active": true
+        });
+
+        assert_eq!(
+            context.interpolate_json(json),
+            json!({
+                "id": 42,
+                "label": "user 42",
+                "friends": [{ "name": "Isaac" }],
+                "active": true
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_share_values_between_clones() {
+        let context = Context::default();
+        context.clone().set("token", json!("abc"));
+
+        assert_eq!(context.get("token"), Some(json!("abc")));
+    }
+}
@@ -0,0 +1,285 @@
+//! The `cookie_jar` module provides a handle to the cookies stored by a
+//! [`Grillon`] client, to inspect them and to pre-seed them from a fixture.
+//!
+//! [`Grillon`]: crate::Grillon
+use crate::dsl::http::Cookie;
+use crate::error::{Error, Result};
+use bytes::Bytes;
+use cookie_store::{CookieStore, RawCookie};
+use http::HeaderValue;
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use url::Url;
+
+/// A shared store of cookies.
+///
+/// The cookie jar is owned by a [`Grillon`] instance and is filled with the
+/// `Set-Cookie` headers of the responses once the cookie store is enabled
+/// with [`Grillon::store_cookies`]. The cookies of the jar are sent with the
+/// requests whose url matches their domain and path.
+///
+/// Cloning a cookie jar gives a handle to the same store, not a copy of the
+/// cookies: a cookie added, removed or loaded through any clone is seen by all
+/// of them, including the one used by the http client of the [`Grillon`]
+/// instance. Use [`CookieJar::save`] and [`CookieJar::load`] to copy the
+/// cookies to another client.
+///
+/// [`Grillon`]: crate::Grillon
+/// [`Grillon::store_cookies`]: crate::Grillon::store_cookies
+#[derive(Clone, Default)]
+pub struct CookieJar {
+    store: Arc<RwLock<CookieStore>>,
+}
+
+impl CookieJar {
+    /// Returns the unexpired cookies sent with a request to the given url.
+    ///
+    /// The `HttpOnly` cookies are only returned for http urls, and the
+    /// `Secure` cookies for https urls.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the url cannot be parsed.
+    pub fn cookies(&self, url: &str) -> Result<Vec<Cookie>> {
+        let url = url.parse::<Url>()?;
+        let store = self.store.read().expect("Cookie jar lock poisoned");
+
+        Ok(store
+            .matches(&url)
+            .into_iter()
+            .filter_map(|cookie| {
+                let mut parsed = Cookie::parse(&cookie.to_string())?;
+                parsed.path = Some(String::from(&cookie.path));
+                parsed.domain = cookie.domain.as_cow().map(|domain| domain.into_owned());
+
+                Some(parsed)
+            })
+            .collect())
+    }
+
+    /// Adds a cookie as if it was set by a response from the given url. The
+    /// cookie is written like the value of a `Set-Cookie` header, such as
+    /// `"session=abc123; Path=/; HttpOnly"`, and replaces the cookie with the
+    /// same name, domain and path.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the url cannot be parsed, or if the cookie is
+    /// invalid or cannot be set from the url.
+    pub fn add(&self, set_cookie: &str, url: &str) -> Result<()> {
+        let url = url.parse::<Url>()?;
+        self.store
+            .write()
+            .expect("Cookie jar lock poisoned")
+            .parse(set_cookie, &url)
+            .map_err(|err| Error::InvalidCookie(set_cookie.to_string(), err.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Removes the cookies with the given name sent with a request to the
+    /// given url. Returns `true` if a cookie was removed.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the url cannot be parsed.
+    pub fn remove(&self, name: &str, url: &str) -> Result<bool> {
+        let url = url.parse::<Url>()?;
+        let mut store = self.store.write().expect("Cookie jar lock poisoned");
+
+        let keys: Vec<(String, String)> = store
+            .matches(&url)
+            .into_iter()
+            .filter(|cookie| cookie.name() == name)
+            .filter_map(|cookie| {
+                let domain = cookie.domain.as_cow()?.into_owned();
+                Some((domain, String::from(&cookie.path)))
+            })
+            .collect();
+        let mut removed = false;
+        for (domain, path) in keys {
+            removed |= store.remove(&domain, &path, name).is_some();
+        }
+
+        Ok(removed)
+    }
+
+    /// Removes all the cookies.
+    pub fn clear(&self) {
+        self.store
+            .write()
+            .expect("Cookie jar lock poisoned")
+            .clear();
+    }
+
+    /// Saves the cookies to a json file, session cookies included, so they can
+    /// be loaded by another client with [`CookieJar::load`].
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the cookies cannot be serialized or if the file
+    /// cannot be written.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut json = Vec::new();
+        {
+            let store = self.store.read().expect("Cookie jar lock poisoned");
+            cookie_store::serde::json::save_incl_expired_and_nonpersistent(&store, &mut json)
+                .map_err(|err| {
+                    Error::InvalidCookieJar(path.display().to_string(), err.to_string())
+                })?;
+        }
+
+        std::fs::write(path, json)
+            .map_err(|err| Error::FileWriteError(path.display().to_string(), err))
+    }
+
+    /// Replaces the cookies with the ones of a json file written by
+    /// [`CookieJar::save`]. The expired cookies are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the file cannot be read or isn't a valid cookie
+    /// jar.
+    pub fn load<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let json = std::fs::read(path)
+            .map_err(|err| Error::FileReadError(path.display().to_string(), err))?;
+        let loaded = cookie_store::serde::json::load(json.as_slice())
+            .map_err(|err| Error::InvalidCookieJar(path.display().to_string(), err.to_string()))?;
+
+        *self.store.write().expect("Cookie jar lock poisoned") = loaded;
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for CookieJar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let store = self.store.read().expect("Cookie jar lock poisoned");
+        f.debug_list()
+            .entries(store.iter_unexpired().map(|cookie| cookie.to_string()))
+            .finish()
+    }
+}
+
+impl reqwest::cookie::CookieStore for CookieJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let cookies = cookie_headers
+            .filter_map(|value| value.to_str().ok())
+            .filter_map(|value| RawCookie::parse(value.to_string()).ok());
+
+        self.store
+            .write()
+            .expect("Cookie jar lock poisoned")
+            .store_response_cookies(cookies, url);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        let cookies = self
+            .store
+            .read()
+            .expect("Cookie jar lock poisoned")
+            .get_request_values(url)
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if cookies.is_empty() {
+            return None;
+        }
+
+        HeaderValue::from_maybe_shared(Bytes::from(cookies)).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CookieJar;
+    use crate::dsl::http::{cookie_attributes, Cookie};
+
+    const URL: &str = "http://localhost:8080/api/users";
+
+    fn names(cookies: Vec<Cookie>) -> Vec<String> {
+        let mut names: Vec<String> = cookies.into_iter().filter_map(|c| c.name).collect();
+        names.sort();
+
+        names
+    }
+
+    #[test]
+    fn it_should_list_the_cookies_of_an_url() {
+        let jar = CookieJar::default();
+        jar.add("session=abc123; Path=/; HttpOnly", URL).unwrap();
+        jar.add("theme=dark; Path=/api", URL).unwrap();
+        jar.add("admin=1; Path=/admin", URL).unwrap();
+
+        assert_eq!(names(jar.cookies(URL).unwrap()), vec!["session", "theme"]);
+        assert_eq!(
+            names(jar.cookies("http://localhost:8080/admin").unwrap()),
+            vec!["admin", "session"]
+        );
+        assert!(jar.cookies("http://example.com/").unwrap().is_empty());
+
+        let session = jar
+            .cookies("http://localhost:8080/")
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(
+            session,
+            Cookie {
+                name: Some("session".to_string()),
+                domain: Some("localhost".to_string()),
+                ..cookie_attributes()
+                    .value("abc123")
+                    .http_only(true)
+                    .secure(false)
+                    .path("/")
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_replace_a_cookie() {
+        let jar = CookieJar::default();
+        jar.add("theme=dark; Path=/", URL).unwrap();
+        jar.add("theme=light; Path=/", URL).unwrap();
+
+        let cookies = jar.cookies(URL).unwrap();
+        assert_eq!(cookies.len(), 1);
+        assert_eq!(cookies[0].value.as_deref(), Some("light"));
+    }
+
+    #[test]
+    fn it_should_remove_and_clear_cookies() {
+        let jar = CookieJar::default();
+        jar.add("session=abc123; Path=/", URL).unwrap();
+        jar.add("theme=dark; Path=/", URL).unwrap();
+
+        assert!(jar.remove("session", URL).unwrap());
+        assert!(!jar.remove("session", URL).unwrap());
+        assert_eq!(names(jar.cookies(URL).unwrap()), vec!["theme"]);
+
+        jar.clear();
+        assert!(jar.cookies(URL).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_should_not_add_invalid_cookies() {
+        let jar = CookieJar::default();
+
+        assert!(jar.add("session", URL).is_err());
+        assert!(jar.add("session=abc123", "not an url").is_err());
+        assert!(jar.add("session=abc123; Domain=example.com", URL).is_err());
+    }
+
+    #[test]
+    fn it_should_share_cookies_between_clones() {
+        let jar = CookieJar::default();
+        jar.clone().add("session=abc123", URL).unwrap();
+
+        assert_eq!(names(jar.cookies(URL).unwrap()), vec!["session"]);
+    }
+}
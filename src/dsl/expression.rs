@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
 use strum::Display;
 
 /// Type representing a condition for assertions.
@@ -24,6 +26,14 @@ pub enum Predicate {
     #[strum(serialize = "should not contain")]
     #[serde(rename = "should not contain")]
     DoesNotContain,
+    /// Actual should start with expected.
+    #[strum(serialize = "should start with")]
+    #[serde(rename = "should start with")]
+    StartsWith,
+    /// Actual should end with expected.
+    #[strum(serialize = "should end with")]
+    #[serde(rename = "should end with")]
+    EndsWith,
     /// Actual should match the regex.
     #[strum(serialize = "should match")]
     #[serde(rename = "should match")]
@@ -36,14 +46,60 @@ pub enum Predicate {
     #[strum(serialize = "should be less than")]
     #[serde(rename = "should be less than")]
     LessThan,
+    /// Actual should be less than or equal to expected.
+    #[strum(serialize = "should be less or equal to")]
+    #[serde(rename = "should be less or equal to")]
+    LessOrEqual,
+    /// Actual should be greater than expected.
+    #[strum(serialize = "should be greater than")]
+    #[serde(rename = "should be greater than")]
+    GreaterThan,
+    /// Actual should be greater than or equal to expected.
+    #[strum(serialize = "should be greater or equal to")]
+    #[serde(rename = "should be greater or equal to")]
+    GreaterOrEqual,
     /// Actual should be between the given closed interval [min, max].
     #[strum(serialize = "should be between")]
     #[serde(rename = "should be between")]
     Between,
+    /// Actual should exist.
+    #[strum(serialize = "should exist")]
+    #[serde(rename = "should exist")]
+    Exists,
+    /// Actual should not exist.
+    #[strum(serialize = "should not exist")]
+    #[serde(rename = "should not exist")]
+    DoesNotExist,
+    /// Actual should have the expected length.
+    #[strum(serialize = "should have length")]
+    #[serde(rename = "should have length")]
+    HasLength,
+    /// Actual should be equal to expected, the numbers being equal within a
+    /// tolerance.
+    #[strum(serialize = "should be approximately")]
+    #[serde(rename = "should be approximately")]
+    IsApprox,
+    /// Actual should be equal to expected, the arrays being compared in any
+    /// order.
+    #[strum(serialize = "should be in any order")]
+    #[serde(rename = "should be in any order")]
+    IsUnordered,
+    /// Actual should be of the expected type.
+    #[strum(serialize = "should be of type")]
+    #[serde(rename = "should be of type")]
+    IsType,
+    /// Actual should include expected.
+    #[strum(serialize = "should include")]
+    #[serde(rename = "should include")]
+    Includes,
     /// Actual should match the given json schema.
     #[strum(serialize = "should match schema")]
     #[serde(rename = "should match schema")]
     Schema,
+    /// Actual should match the stored snapshot.
+    #[strum(serialize = "should match snapshot")]
+    #[serde(rename = "should match snapshot")]
+    MatchesSnapshot,
     /// The absence of predicate for an assertion.
     /// Usually used for an unprocessable assertion.
     #[strum(serialize = "none")]
@@ -67,6 +123,171 @@ pub struct Range<T> {
 #[derive(Deserialize, Debug, PartialEq, Eq)]
 pub struct RegexWrapper<T>(pub T);
 
+/// Represents an expected length, either a number or an [`Expression`] such
+/// as [`is_between`].
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+pub struct Length<T>(pub T);
+
+/// Defines how the arrays of an expected subset are compared to the actual
+/// arrays.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ArrayMatching {
+    /// The arrays have the same length and their items are included in the
+    /// actual ones, in the same order.
+    #[default]
+    Exact,
+    /// The expected items are included in the first actual items, in the
+    /// same order.
+    Prefix,
+    /// Each expected item is included in a distinct actual item, in any
+    /// order.
+    AnyOrder,
+}
+
+/// Represents an expected subset of the actual value.
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+pub struct Subset<T> {
+    /// The expected subset.
+    pub value: T,
+    /// How the arrays are compared.
+    pub arrays: ArrayMatching,
+}
+
+/// Represents a snapshot file of the actual value, see [`matches_snapshot`].
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The name of the snapshot, used as the file name.
+    pub name: String,
+    /// The directory of the snapshot file. Defaults to the
+    /// `GRILLON_SNAPSHOT_DIR` environment variable, or `tests/snapshots`.
+    pub dir: Option<PathBuf>,
+    /// The json paths of the values redacted before the comparison.
+    pub redactions: Vec<String>,
+}
+
+/// Represents an expected value whose numbers are compared within a
+/// tolerance, see [`approx`].
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Approx<T> {
+    /// The expected value.
+    pub value: T,
+    /// The maximum absolute difference between two equal numbers.
+    pub epsilon: f64,
+}
+
+/// Represents an expected json value compared with options, see
+/// [`Expression::ignoring`], [`Expression::tolerance`] and
+/// [`Expression::unordered`].
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct JsonEquality<T> {
+    /// The expected value.
+    pub value: T,
+    /// The json paths of the values excluded from the comparison.
+    pub ignored: Vec<String>,
+    /// The maximum absolute difference between two equal numbers, the
+    /// numbers are compared strictly when `None`.
+    pub tolerance: Option<f64>,
+    /// Whether the arrays are compared in any order.
+    pub unordered: bool,
+}
+
+/// Conversion into a [`JsonEquality`], implemented for the expected values of
+/// the json equality predicates.
+pub trait IntoJsonEquality {
+    /// The type of the expected value.
+    type Value;
+
+    /// Converts the expected value into a [`JsonEquality`].
+    fn into_json_equality(self) -> JsonEquality<Self::Value>;
+}
+
+macro_rules! impl_into_json_equality {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoJsonEquality for $ty {
+                type Value = Self;
+
+                fn into_json_equality(self) -> JsonEquality<Self> {
+                    JsonEquality {
+                        value: self,
+                        ignored: vec![],
+                        tolerance: None,
+                        unordered: false,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_into_json_equality!(Value, &str, String, PathBuf);
+
+impl<T> IntoJsonEquality for JsonEquality<T> {
+    type Value = T;
+
+    fn into_json_equality(self) -> JsonEquality<T> {
+        self
+    }
+}
+
+impl<T: IntoJsonEquality> Expression<T> {
+    /// Excludes the values matched by the given json paths from the
+    /// comparison, in both the actual and the expected values.
+    ///
+    /// With `json_path`, the paths are relative to the values found at the
+    /// asserted path.
+    pub fn ignoring<I, P>(self, paths: I) -> Expression<JsonEquality<T::Value>>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<String>,
+    {
+        let mut value = self.value.into_json_equality();
+        value.ignored.extend(paths.into_iter().map(Into::into));
+
+        Expression {
+            predicate: self.predicate,
+            value,
+        }
+    }
+
+    /// Compares the numbers of the actual and the expected values within the
+    /// given tolerance: two numbers are equal when their absolute difference
+    /// is less than or equal to `epsilon`.
+    pub fn tolerance(self, epsilon: f64) -> Expression<JsonEquality<T::Value>> {
+        let mut value = self.value.into_json_equality();
+        value.tolerance = Some(epsilon);
+
+        Expression {
+            predicate: self.predicate,
+            value,
+        }
+    }
+
+    /// Compares the arrays of the actual and the expected values as
+    /// multisets: the arrays are equal when they have the same items, in any
+    /// order. Nested arrays are compared the same way.
+    ///
+    /// Combined with [`tolerance`](Self::tolerance), the items are paired
+    /// whatever their index, two items being equal when their numbers are
+    /// within the tolerance.
+    pub fn unordered(self) -> Expression<JsonEquality<T::Value>> {
+        let mut value = self.value.into_json_equality();
+        value.unordered = true;
+
+        Expression {
+            predicate: self.predicate,
+            value,
+        }
+    }
+}
+
+/// Represents the expected presence of a value, without expecting any value
+/// in particular. The [`Predicate`] tells if the value should be present or
+/// absent.
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+pub struct Presence;
+
 /// Represents an expected `value` associated to a [`Predicate`] to run against
 /// another `value`.
 ///
@@ -125,16 +346,135 @@ pub fn does_not_match<T>(re: T) -> Expression<RegexWrapper<T>> {
     }
 }
 
+/// Creates an expression to assert that the actual value is equal to the
+/// expected one, two numbers being equal when their absolute difference is
+/// less than or equal to `epsilon`. Numbers nested in arrays and objects are
+/// compared the same way.
+pub fn approx<T>(value: T, epsilon: f64) -> Expression<Approx<T>> {
+    Expression {
+        predicate: Predicate::IsApprox,
+        value: Approx { value, epsilon },
+    }
+}
+
+/// Creates an expression to assert the length of the actual value. The
+/// expected length is either a number or a range built with [`is_between`].
+pub fn has_length<T>(length: T) -> Expression<Length<T>> {
+    Expression {
+        predicate: Predicate::HasLength,
+        value: Length(length),
+    }
+}
+
+/// Creates an expression to assert that the actual value includes the
+/// expected one. Objects can have more keys than the expected ones, the
+/// arrays are compared with [`ArrayMatching::Exact`] unless configured with
+/// [`Expression::arrays`].
+pub fn includes<T>(value: T) -> Expression<Subset<T>> {
+    Expression {
+        predicate: Predicate::Includes,
+        value: Subset {
+            value,
+            arrays: ArrayMatching::default(),
+        },
+    }
+}
+
+impl<T> Expression<Subset<T>> {
+    /// Sets how the arrays of the expected subset are compared.
+    pub fn arrays(mut self, arrays: ArrayMatching) -> Self {
+        self.value.arrays = arrays;
+        self
+    }
+}
+
+/// Creates an expression to assert that the json body or the headers match
+/// the snapshot stored in the file `<name>.json`, with [`Assert::json_body`]
+/// or [`Assert::headers_snapshot`].
+///
+/// The snapshot is written with the actual value the first time the assertion
+/// runs, and the assertion passes. An existing snapshot is rewritten when the
+/// `GRILLON_UPDATE_SNAPSHOTS` environment variable is set to `1`. The headers
+/// are stored sorted by name.
+///
+/// [`Assert::json_body`]: crate::Assert::json_body
+/// [`Assert::headers_snapshot`]: crate::Assert::headers_snapshot
+pub fn matches_snapshot<N: Into<String>>(name: N) -> Expression<Snapshot> {
+    Expression {
+        predicate: Predicate::MatchesSnapshot,
+        value: Snapshot {
+            name: name.into(),
+            dir: None,
+            redactions: vec![],
+        },
+    }
+}
+
+impl Expression<Snapshot> {
+    /// Sets the directory of the snapshot file.
+    pub fn dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.value.dir = Some(dir.into());
+        self
+    }
+
+    /// Replaces the values matched by the given json paths with
+    /// `"[redacted]"`, in the actual value and in the written snapshot.
+    pub fn redacting<I, P>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<String>,
+    {
+        self.value
+            .redactions
+            .extend(paths.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Creates an expression to assert that the actual value exists.
+pub fn exists() -> Expression<Presence> {
+    Expression {
+        predicate: Predicate::Exists,
+        value: Presence,
+    }
+}
+
+/// Creates an expression to assert that the actual value doesn't exist.
+pub fn does_not_exist() -> Expression<Presence> {
+    Expression {
+        predicate: Predicate::DoesNotExist,
+        value: Presence,
+    }
+}
+
+/// Creates an expression to assert that the actual value is absent, see
+/// [`does_not_exist`].
+pub fn is_absent() -> Expression<Presence> {
+    does_not_exist()
+}
+
 predicate!(
     /// Creates an expression to assert that the actual value is strictly equal to the expected one.
+    ///
+    /// With `json_body` and `json_path`, a string of the expected json value made of a single typed
+    /// placeholder, such as `"{{uuid}}"` or `"{{regex:^ord_[0-9]+$}}"`, matches any actual value
+    /// of the described shape. The items of an array are resolved against the actual items at
+    /// the same index, also when the arrays are compared in any order.
     is,
     Predicate::Is
 );
 predicate!(
     /// Creates an expression to assert that the actual value is strictly not equal to the expected one.
+    ///
+    /// The typed placeholders of the expected json value are resolved as with [`is`].
     is_not,
     Predicate::IsNot
 );
+predicate!(
+    /// Creates an expression to assert that the actual value is equal to the expected one, the arrays being compared in any order.
+    is_unordered,
+    Predicate::IsUnordered
+);
 predicate!(
     /// Creates an expression to assert that the actual value contains the expected one.
     contains,
@@ -145,11 +485,41 @@ predicate!(
     does_not_contain,
     Predicate::DoesNotContain
 );
+predicate!(
+    /// Creates an expression to assert that the actual value starts with the expected one.
+    starts_with,
+    Predicate::StartsWith
+);
+predicate!(
+    /// Creates an expression to assert that the actual value ends with the expected one.
+    ends_with,
+    Predicate::EndsWith
+);
 predicate!(
     /// Creates an expression to assert that the actual value is inferior to the provided value.
     is_less_than,
     Predicate::LessThan
 );
+predicate!(
+    /// Creates an expression to assert that the actual value is inferior or equal to the provided value.
+    is_less_or_equal,
+    Predicate::LessOrEqual
+);
+predicate!(
+    /// Creates an expression to assert that the actual value is superior to the provided value.
+    is_greater_than,
+    Predicate::GreaterThan
+);
+predicate!(
+    /// Creates an expression to assert that the actual value is superior or equal to the provided value.
+    is_greater_or_equal,
+    Predicate::GreaterOrEqual
+);
+predicate!(
+    /// Creates an expression to assert that the actual value is of the expected type.
+    is_type,
+    Predicate::IsType
+);
 predicate!(
     /// Creates an expression to assert that the actual value matches the json schema.
     schema,
@@ -166,11 +536,24 @@ mod tests {
     #[test_case(Value::String(String::from("should not be")), Predicate::IsNot; "Failed to deserialize predicate IsNot")]
     #[test_case(Value::String(String::from("should contain")), Predicate::Contains; "Failed to deserialize predicate Contains")]
     #[test_case(Value::String(String::from("should not contain")), Predicate::DoesNotContain; "Failed to deserialize predicate DoesNotContain")]
+    #[test_case(Value::String(String::from("should start with")), Predicate::StartsWith; "Failed to deserialize predicate StartsWith")]
+    #[test_case(Value::String(String::from("should end with")), Predicate::EndsWith; "Failed to deserialize predicate EndsWith")]
     #[test_case(Value::String(String::from("should match")), Predicate::Matches; "Failed to deserialize predicate Matches")]
     #[test_case(Value::String(String::from("should not match")), Predicate::DoesNotMatch; "Failed to deserialize predicate DoesNotMatch")]
     #[test_case(Value::String(String::from("should be less than")), Predicate::LessThan; "Failed to deserialize predicate LessThan")]
+    #[test_case(Value::String(String::from("should be less or equal to")), Predicate::LessOrEqual; "Failed to deserialize predicate LessOrEqual")]
+    #[test_case(Value::String(String::from("should be greater than")), Predicate::GreaterThan; "Failed to deserialize predicate GreaterThan")]
+    #[test_case(Value::String(String::from("should be greater or equal to")), Predicate::GreaterOrEqual; "Failed to deserialize predicate GreaterOrEqual")]
     #[test_case(Value::String(String::from("should be between")), Predicate::Between; "Failed to deserialize predicate Between")]
+    #[test_case(Value::String(String::from("should exist")), Predicate::Exists; "Failed to deserialize predicate Exists")]
+    #[test_case(Value::String(String::from("should not exist")), Predicate::DoesNotExist; "Failed to deserialize predicate DoesNotExist")]
+    #[test_case(Value::String(String::from("should have length")), Predicate::HasLength; "Failed to deserialize predicate HasLength")]
+    #[test_case(Value::String(String::from("should be approximately")), Predicate::IsApprox; "Failed to deserialize predicate IsApprox")]
+    #[test_case(Value::String(String::from("should be in any order")), Predicate::IsUnordered; "Failed to deserialize predicate IsUnordered")]
+    #[test_case(Value::String(String::from("should be of type")), Predicate::IsType; "Failed to deserialize predicate IsType")]
+    #[test_case(Value::String(String::from("should include")), Predicate::Includes; "Failed to deserialize predicate Includes")]
     #[test_case(Value::String(String::from("should match schema")), Predicate::Schema; "Failed to deserialize predicate Schema")]
+    #[test_case(Value::String(String::from("should match snapshot")), Predicate::MatchesSnapshot; "Failed to deserialize predicate MatchesSnapshot")]
 
     fn deser_predicates(json_predicate: Value, predicate: Predicate) {
         assert_eq!(
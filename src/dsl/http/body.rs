@@ -1,25 +1,29 @@
 use std::path::PathBuf;
 
 use crate::{
-    assertion::traits::{Equality, JsonSchema},
+    assertion::traits::{
+        Equality, HasLength, Inclusion, JsonSchema, OfType, SnapshotMatching, UnorderedEquality,
+    },
     assertion::Assertion,
-    dsl::expression::Predicate::{self, Is, IsNot, Schema},
+    dsl::{
+        expression::Predicate::{self, Between, Is, IsNot, Schema},
+        Expression, JsonEquality, JsonType, Length, Range, Snapshot, Subset,
+    },
     LogSettings,
 };
 use serde_json::Value;
 
 /// Http json body DSL to assert body of a response.
 pub trait JsonBodyDsl<T> {
-    /// Asserts that the json response body is strictly equals to the provided value.
-    fn is(&self, actual: T) -> Assertion<Value>;
-    /// Asserts that the json response body is strictly not equals to the provided value.
-    fn is_not(&self, actual: T) -> Assertion<Value>;
-    /// Asserts that the json response body matches the json schema.
-    fn schema(&self, schema: T) -> Assertion<Value>;
     /// Evaluates the json body assertion to run based on the [`Predicate`].
+    fn eval(&self, actual: T, predicate: Predicate, log_settings: &LogSettings)
+        -> Assertion<Value>;
+}
+
+impl JsonBodyDsl<Value> for Value {
     fn eval(
         &self,
-        actual: T,
+        actual: Value,
         predicate: Predicate,
         log_settings: &LogSettings,
     ) -> Assertion<Value> {
@@ -27,18 +31,190 @@ pub trait JsonBodyDsl<T> {
             Is => self.is(actual).assert(log_settings),
             IsNot => self.is_not(actual).assert(log_settings),
             Schema => self.schema(actual).assert(log_settings),
+            Predicate::IsUnordered => actual.unordered_eq(self).assert(log_settings),
             _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
         }
     }
 }
 
-impl JsonBodyDsl<Value> for Value {
+impl JsonBodyDsl<Value> for &str {
+    fn eval(
+        &self,
+        actual: Value,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Is => self.is(actual).assert(log_settings),
+            IsNot => self.is_not(actual).assert(log_settings),
+            Schema => self.schema(actual).assert(log_settings),
+            Predicate::IsUnordered => actual.unordered_eq(*self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
+        }
+    }
+}
+
+impl JsonBodyDsl<Value> for String {
+    fn eval(
+        &self,
+        actual: Value,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Is => self.is(actual).assert(log_settings),
+            IsNot => self.is_not(actual).assert(log_settings),
+            Schema => self.schema(actual).assert(log_settings),
+            Predicate::IsUnordered => actual.unordered_eq(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
+        }
+    }
+}
+
+impl JsonBodyDsl<Value> for PathBuf {
+    fn eval(
+        &self,
+        actual: Value,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Is => self.is(actual).assert(log_settings),
+            IsNot => self.is_not(actual).assert(log_settings),
+            Schema => self.schema(actual).assert(log_settings),
+            Predicate::IsUnordered => actual.unordered_eq(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
+        }
+    }
+}
+
+impl<T: JsonBodyValueDsl<Value>> JsonBodyDsl<Value> for JsonEquality<T> {
+    fn eval(
+        &self,
+        actual: Value,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        let assertion = match predicate {
+            Is => self.value.is(actual),
+            IsNot => self.value.is_not(actual),
+            _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
+        };
+
+        assertion
+            .ignoring(&self.ignored)
+            .within_tolerance(self.tolerance, self.unordered)
+            .in_any_order(self.unordered)
+            .assert(log_settings)
+    }
+}
+
+impl JsonBodyDsl<Value> for Subset<Value> {
+    fn eval(
+        &self,
+        actual: Value,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Predicate::Includes => actual.includes(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
+        }
+    }
+}
+
+impl JsonBodyDsl<Value> for Snapshot {
+    fn eval(
+        &self,
+        actual: Value,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Predicate::MatchesSnapshot => actual.matches_snapshot(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
+        }
+    }
+}
+
+impl JsonBodyDsl<Value> for JsonType {
+    fn eval(
+        &self,
+        actual: Value,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Predicate::IsType => actual.of_type(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
+        }
+    }
+}
+
+/// Implements the json body DSL for the lengths given as primitive integer
+/// types.
+macro_rules! impl_json_body_dsl_for_lengths {
+    ($($ty:ty),*) => {
+        $(
+            impl JsonBodyDsl<Value> for Length<$ty> {
+                fn eval(
+                    &self,
+                    actual: Value,
+                    predicate: Predicate,
+                    log_settings: &LogSettings,
+                ) -> Assertion<Value> {
+                    match predicate {
+                        Predicate::HasLength => actual.length_eq(&Value::from(self.0)).assert(log_settings),
+                        _ => unimplemented!("Invalid predicate for the json body DSL: {predicate}"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_json_body_dsl_for_lengths!(i32, i64, u32, u64, usize);
+
+impl<T> JsonBodyDsl<Value> for Length<Expression<Range<T>>>
+where
+    T: Clone + Into<Value>,
+{
+    fn eval(
+        &self,
+        actual: Value,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match (predicate, &self.0.predicate) {
+            (Predicate::HasLength, Between) => {
+                let min: Value = self.0.value.left.clone().into();
+                let max: Value = self.0.value.right.clone().into();
+                actual.length_in_range(&min, &max).assert(log_settings)
+            }
+            (predicate, _) => {
+                unimplemented!("Invalid predicate for the json body DSL: {predicate}")
+            }
+        }
+    }
+}
+
+/// Http json body DSL to assert the json body equality and schema.
+pub trait JsonBodyValueDsl<T>: JsonBodyDsl<T> {
+    /// Asserts that the json response body is strictly equals to the provided value.
+    fn is(&self, actual: T) -> Assertion<Value>;
+    /// Asserts that the json response body is strictly not equals to the provided value.
+    fn is_not(&self, actual: T) -> Assertion<Value>;
+    /// Asserts that the json response body matches the json schema.
+    fn schema(&self, schema: T) -> Assertion<Value>;
+}
+
+impl JsonBodyValueDsl<Value> for Value {
     fn is(&self, actual: Value) -> Assertion<Value> {
-        actual.is_eq(self)
+        actual.is_eq(self).with_placeholders()
     }
 
     fn is_not(&self, actual: Value) -> Assertion<Value> {
-        actual.is_ne(self)
+        actual.is_ne(self).with_placeholders()
     }
 
     fn schema(&self, actual: Value) -> Assertion<Value> {
@@ -46,13 +222,13 @@ impl JsonBodyDsl<Value> for Value {
     }
 }
 
-impl JsonBodyDsl<Value> for &str {
+impl JsonBodyValueDsl<Value> for &str {
     fn is(&self, actual: Value) -> Assertion<Value> {
-        actual.is_eq(*self)
+        actual.is_eq(*self).with_placeholders()
     }
 
     fn is_not(&self, actual: Value) -> Assertion<Value> {
-        actual.is_ne(*self)
+        actual.is_ne(*self).with_placeholders()
     }
 
     fn schema(&self, actual: Value) -> Assertion<Value> {
@@ -60,13 +236,13 @@ impl JsonBodyDsl<Value> for &str {
     }
 }
 
-impl JsonBodyDsl<Value> for String {
+impl JsonBodyValueDsl<Value> for String {
     fn is(&self, actual: Value) -> Assertion<Value> {
-        actual.is_eq(self)
+        actual.is_eq(self).with_placeholders()
     }
 
     fn is_not(&self, actual: Value) -> Assertion<Value> {
-        actual.is_ne(self)
+        actual.is_ne(self).with_placeholders()
     }
 
     fn schema(&self, actual: Value) -> Assertion<Value> {
@@ -74,13 +250,13 @@ impl JsonBodyDsl<Value> for String {
     }
 }
 
-impl JsonBodyDsl<Value> for PathBuf {
+impl JsonBodyValueDsl<Value> for PathBuf {
     fn is(&self, actual: Value) -> Assertion<Value> {
-        actual.is_eq(self)
+        actual.is_eq(self).with_placeholders()
     }
 
     fn is_not(&self, actual: Value) -> Assertion<Value> {
-        actual.is_ne(self)
+        actual.is_ne(self).with_placeholders()
     }
 
     fn schema(&self, actual: Value) -> Assertion<Value> {
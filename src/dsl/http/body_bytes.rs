@@ -0,0 +1,140 @@
+use bytes::Bytes;
+
+use crate::{
+    assertion::{
+        traits::{Container, Equality, Matching},
+        Assertion,
+    },
+    dsl::{
+        expression::Predicate::{self, Contains, DoesNotContain, DoesNotMatch, Is, IsNot, Matches},
+        RegexWrapper,
+    },
+    LogSettings,
+};
+
+/// Http body bytes DSL to assert the raw body of a response.
+pub trait BodyBytesDsl<T> {
+    /// Evaluates the body bytes assertion to run based on the [`Predicate`].
+    fn eval(&self, actual: T, predicate: Predicate, log_settings: &LogSettings)
+        -> Assertion<Bytes>;
+}
+
+/// Macro to implement the body bytes DSL for types that can be viewed as a
+/// slice of bytes.
+macro_rules! impl_body_bytes_dsl {
+    ($([$($gen:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            impl<$($gen)*> BodyBytesDsl<Bytes> for $ty {
+                fn eval(
+                    &self,
+                    actual: Bytes,
+                    predicate: Predicate,
+                    log_settings: &LogSettings,
+                ) -> Assertion<Bytes> {
+                    match predicate {
+                        Is => self.is(actual).assert(log_settings),
+                        IsNot => self.is_not(actual).assert(log_settings),
+                        Contains => self.contains(actual).assert(log_settings),
+                        DoesNotContain => self.does_not_contain(actual).assert(log_settings),
+                        _ => unimplemented!("Invalid predicate for the body bytes DSL: {predicate}"),
+                    }
+                }
+            }
+
+            impl<$($gen)*> BodyBytesValueDsl<Bytes> for $ty {
+                fn is(&self, actual: Bytes) -> Assertion<Bytes> {
+                    actual.is_eq(&self[..])
+                }
+
+                fn is_not(&self, actual: Bytes) -> Assertion<Bytes> {
+                    actual.is_ne(&self[..])
+                }
+
+                fn contains(&self, actual: Bytes) -> Assertion<Bytes> {
+                    actual.has(&self[..])
+                }
+
+                fn does_not_contain(&self, actual: Bytes) -> Assertion<Bytes> {
+                    actual.has_not(&self[..])
+                }
+            }
+        )*
+    };
+}
+
+impl_body_bytes_dsl!(
+    ['a] &'a [u8],
+    [const N: usize] &[u8; N],
+    [] Vec<u8>,
+    [] Bytes,
+);
+
+impl BodyBytesDsl<Bytes> for RegexWrapper<&str> {
+    fn eval(
+        &self,
+        actual: Bytes,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Bytes> {
+        match predicate {
+            Matches => self.matches(actual).assert(log_settings),
+            DoesNotMatch => self.does_not_match(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the body bytes DSL: {predicate}"),
+        }
+    }
+}
+
+impl BodyBytesDsl<Bytes> for RegexWrapper<String> {
+    fn eval(
+        &self,
+        actual: Bytes,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Bytes> {
+        match predicate {
+            Matches => self.matches(actual).assert(log_settings),
+            DoesNotMatch => self.does_not_match(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the body bytes DSL: {predicate}"),
+        }
+    }
+}
+
+/// Http body bytes DSL to assert the raw body equality and content.
+pub trait BodyBytesValueDsl<T>: BodyBytesDsl<T> {
+    /// Asserts that the raw body is strictly equal to the provided bytes.
+    fn is(&self, actual: T) -> Assertion<Bytes>;
+    /// Asserts that the raw body is strictly not equal to the provided bytes.
+    fn is_not(&self, actual: T) -> Assertion<Bytes>;
+    /// Asserts that the raw body contains the provided sequence of bytes.
+    fn contains(&self, actual: T) -> Assertion<Bytes>;
+    /// Asserts that the raw body does not contain the provided sequence of bytes.
+    fn does_not_contain(&self, actual: T) -> Assertion<Bytes>;
+}
+
+/// Http body bytes regex DSL.
+pub trait BodyBytesRegexDsl<T>: BodyBytesDsl<T> {
+    /// Asserts that the raw body matches the regex.
+    fn matches(&self, actual: T) -> Assertion<Bytes>;
+    /// Asserts that the raw body does not match the regex.
+    fn does_not_match(&self, actual: T) -> Assertion<Bytes>;
+}
+
+impl BodyBytesRegexDsl<Bytes> for RegexWrapper<&str> {
+    fn matches(&self, actual: Bytes) -> Assertion<Bytes> {
+        actual.is_match(self.0)
+    }
+
+    fn does_not_match(&self, actual: Bytes) -> Assertion<Bytes> {
+        actual.is_not_match(self.0)
+    }
+}
+
+impl BodyBytesRegexDsl<Bytes> for RegexWrapper<String> {
+    fn matches(&self, actual: Bytes) -> Assertion<Bytes> {
+        actual.is_match(self.0.as_str())
+    }
+
+    fn does_not_match(&self, actual: Bytes) -> Assertion<Bytes> {
+        actual.is_not_match(self.0.as_str())
+    }
+}
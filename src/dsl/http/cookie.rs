@@ -0,0 +1,318 @@
+//! The `http::cookie` DSL provides built-in types and functions to perform
+//! declarative assertions against the cookies set by an http response.
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{
+    assertion::{
+        traits::{Equality, Existence, Inclusion},
+        Assertion, AssertionResult, Hand, UnprocessableReason,
+    },
+    dsl::{
+        expression::Predicate::{self, DoesNotExist, Exists, Includes, Is, IsNot},
+        Part, Presence, Subset,
+    },
+    LogSettings,
+};
+
+/// The `SameSite` attribute of a cookie.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SameSite {
+    /// The cookie is only sent with same-site requests.
+    Strict,
+    /// The cookie is also sent when navigating to the site from another one.
+    Lax,
+    /// The cookie is sent with all requests.
+    None,
+}
+
+/// A cookie set by a `Set-Cookie` header.
+///
+/// When built as an expected value with [`cookie_attributes`], only the set
+/// attributes are asserted.
+#[derive(Serialize, Deserialize, Default, PartialEq, Eq, Clone)]
+pub struct Cookie {
+    /// The name of the cookie.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The value of the cookie.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Whether the cookie has the `HttpOnly` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_only: Option<bool>,
+    /// Whether the cookie has the `Secure` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secure: Option<bool>,
+    /// The `SameSite` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub same_site: Option<SameSite>,
+    /// The `Path` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// The `Domain` attribute, without its leading dot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// The `Max-Age` attribute, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<i64>,
+    /// The `Expires` attribute, as received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+}
+
+/// Creates the expected attributes of a cookie, to use with
+/// [`includes`](crate::dsl::includes).
+///
+/// ```rust
+/// use grillon::{Result, Grillon};
+/// use grillon::dsl::{includes, http::{cookie_attributes, SameSite}};
+///
+/// #[tokio::test]
+/// async fn check_session_cookie() -> Result<()> {
+///    Grillon::new("https://example.com")?
+///        .post("login")
+///        .assert()
+///        .await
+///        .cookie(
+///            "session",
+///            includes(
+///                cookie_attributes()
+///                    .http_only(true)
+///                    .secure(true)
+///                    .same_site(SameSite::Lax)
+///                    .path("/"),
+///            ),
+///        );
+///
+///    Ok(())
+/// }
+/// ```
+pub fn cookie_attributes() -> Cookie {
+    Cookie::default()
+}
+
+impl Cookie {
+    /// Parses the value of a `Set-Cookie` header. The unknown attributes are
+    /// ignored, as well as the attributes with an invalid value.
+    ///
+    /// Returns `None` if the header doesn't start with a `name=value` pair.
+    pub fn parse(set_cookie: &str) -> Option<Self> {
+        let mut parts = set_cookie.split(';');
+        let (name, value) = parts.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = Cookie {
+            name: Some(name.to_string()),
+            value: Some(value.trim().trim_matches('"').to_string()),
+            http_only: Some(false),
+            secure: Some(false),
+            ..Default::default()
+        };
+
+        for attribute in parts {
+            let (key, value) = match attribute.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => (attribute.trim(), ""),
+            };
+
+            match key.to_ascii_lowercase().as_str() {
+                "httponly" => cookie.http_only = Some(true),
+                "secure" => cookie.secure = Some(true),
+                "samesite" => {
+                    cookie.same_site = match value.to_ascii_lowercase().as_str() {
+                        "strict" => Some(SameSite::Strict),
+                        "lax" => Some(SameSite::Lax),
+                        "none" => Some(SameSite::None),
+                        _ => cookie.same_site,
+                    }
+                }
+                "path" if !value.is_empty() => cookie.path = Some(value.to_string()),
+                "domain" if !value.is_empty() => {
+                    cookie.domain = Some(value.trim_start_matches('.').to_ascii_lowercase())
+                }
+                "max-age" => cookie.max_age = value.parse().ok().or(cookie.max_age),
+                "expires" if !value.is_empty() => cookie.expires = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        Some(cookie)
+    }
+
+    /// Sets the expected value.
+    pub fn value<V: Into<String>>(mut self, value: V) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the `HttpOnly` attribute is expected.
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.http_only = Some(http_only);
+        self
+    }
+
+    /// Sets whether the `Secure` attribute is expected.
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = Some(secure);
+        self
+    }
+
+    /// Sets the expected `SameSite` attribute.
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// Sets the expected `Path` attribute.
+    pub fn path<P: Into<String>>(mut self, path: P) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Sets the expected `Domain` attribute.
+    pub fn domain<D: Into<String>>(mut self, domain: D) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Sets the expected `Max-Age` attribute, in seconds.
+    pub fn max_age(mut self, max_age: i64) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Sets the expected `Expires` attribute, such as
+    /// `"Wed, 21 Oct 2026 07:28:00 GMT"`.
+    pub fn expires<E: Into<String>>(mut self, expires: E) -> Self {
+        self.expires = Some(expires.into());
+        self
+    }
+}
+
+// Only the set attributes are displayed, an expected cookie usually has a few
+// of them.
+impl fmt::Debug for Cookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Cookie");
+        macro_rules! field {
+            ($($field:ident),*) => {
+                $(if let Some(value) = &self.$field {
+                    debug.field(stringify!($field), value);
+                })*
+            };
+        }
+        field!(name, value, http_only, secure, same_site, path, domain, max_age, expires);
+
+        debug.finish()
+    }
+}
+
+/// Http cookie DSL to assert a cookie set by a response.
+pub trait CookieDsl<T> {
+    /// Evaluates the cookie assertion to run based on the [`Predicate`].
+    fn eval(
+        &self,
+        actual: T,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Cookie>;
+
+    /// Evaluates the cookie assertion when no `Set-Cookie` header of the
+    /// response sets the cookie. The assertion is unprocessable unless the
+    /// expression handles the absence of the cookie.
+    fn eval_missing(
+        &self,
+        name: &str,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Cookie> {
+        Assertion {
+            part: Part::Cookie,
+            predicate,
+            left: Hand::Empty,
+            right: Hand::Empty,
+            result: AssertionResult::Unprocessable(UnprocessableReason::MissingCookie(
+                name.to_string(),
+            )),
+        }
+        .assert(log_settings)
+    }
+}
+
+impl CookieDsl<Cookie> for &str {
+    fn eval(
+        &self,
+        actual: Cookie,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Cookie> {
+        match predicate {
+            Is => actual.is_eq(*self).assert(log_settings),
+            IsNot => actual.is_ne(*self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the cookie DSL: {predicate}"),
+        }
+    }
+}
+
+impl CookieDsl<Cookie> for String {
+    fn eval(
+        &self,
+        actual: Cookie,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Cookie> {
+        match predicate {
+            Is => actual.is_eq(self.as_str()).assert(log_settings),
+            IsNot => actual.is_ne(self.as_str()).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the cookie DSL: {predicate}"),
+        }
+    }
+}
+
+impl CookieDsl<Cookie> for Subset<Cookie> {
+    fn eval(
+        &self,
+        actual: Cookie,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Cookie> {
+        match predicate {
+            Includes => actual.includes(&self.value).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the cookie DSL: {predicate}"),
+        }
+    }
+}
+
+impl CookieDsl<Cookie> for Presence {
+    fn eval(
+        &self,
+        actual: Cookie,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Cookie> {
+        let actual = Some(actual);
+        match predicate {
+            Exists => actual.is_present().assert(log_settings),
+            DoesNotExist => actual.is_absent().assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the cookie DSL: {predicate}"),
+        }
+    }
+
+    fn eval_missing(
+        &self,
+        _name: &str,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Cookie> {
+        let actual: Option<Cookie> = None;
+        match predicate {
+            Exists => actual.is_present().assert(log_settings),
+            DoesNotExist => actual.is_absent().assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the cookie DSL: {predicate}"),
+        }
+    }
+}
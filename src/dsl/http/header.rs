@@ -1,43 +1,178 @@
 use http::HeaderValue;
 
 use crate::{
-    assertion::{traits::Equality, types::Header, Assertion},
-    dsl::expression::Predicate,
+    assertion::{
+        traits::{Affix, Container, Equality, Existence, Matching},
+        types::Header,
+        Assertion, AssertionResult, Hand, UnprocessableReason,
+    },
+    dsl::{
+        expression::Predicate::{
+            self, Contains, DoesNotContain, DoesNotMatch, EndsWith, Is, IsNot, Matches, StartsWith,
+        },
+        Part, Presence, RegexWrapper,
+    },
     LogSettings,
 };
 
 /// Http header DSL to assert a single header from a response.
 pub trait HeaderDsl<T> {
-    /// Asserts the header is strictly equal to the provided ones.
-    fn is(&self, actual: T) -> Assertion<Header>;
-    /// Asserts the header is strictly not equal to the provided ones.
-    fn is_not(&self, actual: T) -> Assertion<Header>;
     /// Evaluates the header assertion to run based on the [`Predicate`].
     fn eval(
         &self,
         actual: T,
         predicate: Predicate,
         log_settings: &LogSettings,
+    ) -> Assertion<Header>;
+
+    /// Evaluates the header assertion when the header is missing from the
+    /// response. The assertion is unprocessable unless the expression
+    /// handles the absence of the header.
+    fn eval_missing(&self, predicate: Predicate, log_settings: &LogSettings) -> Assertion<Header> {
+        Assertion {
+            part: Part::Header,
+            predicate,
+            left: Hand::Empty,
+            right: Hand::Empty,
+            result: AssertionResult::Unprocessable(UnprocessableReason::MissingHeader),
+        }
+        .assert(log_settings)
+    }
+}
+
+impl HeaderDsl<HeaderValue> for &str {
+    fn eval(
+        &self,
+        actual: HeaderValue,
+        predicate: Predicate,
+        log_settings: &LogSettings,
     ) -> Assertion<Header> {
         match predicate {
-            Predicate::Is => self.is(actual).assert(log_settings),
-            Predicate::IsNot => self.is_not(actual).assert(log_settings),
+            Is => self.is(actual).assert(log_settings),
+            IsNot => self.is_not(actual).assert(log_settings),
+            Contains => self.contains(actual).assert(log_settings),
+            DoesNotContain => self.does_not_contain(actual).assert(log_settings),
+            StartsWith => self.starts_with(actual).assert(log_settings),
+            EndsWith => self.ends_with(actual).assert(log_settings),
             _ => unimplemented!("Invalid predicate for the header DSL: {predicate}"),
         }
     }
 }
 
-impl HeaderDsl<HeaderValue> for &str {
-    fn is(&self, actual: HeaderValue) -> Assertion<Header> {
-        actual.is_eq(self)
+impl HeaderDsl<HeaderValue> for String {
+    fn eval(
+        &self,
+        actual: HeaderValue,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Header> {
+        match predicate {
+            Is => self.is(actual).assert(log_settings),
+            IsNot => self.is_not(actual).assert(log_settings),
+            Contains => self.contains(actual).assert(log_settings),
+            DoesNotContain => self.does_not_contain(actual).assert(log_settings),
+            StartsWith => self.starts_with(actual).assert(log_settings),
+            EndsWith => self.ends_with(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header DSL: {predicate}"),
+        }
     }
+}
 
-    fn is_not(&self, actual: HeaderValue) -> Assertion<Header> {
-        actual.is_ne(self)
+impl HeaderDsl<HeaderValue> for HeaderValue {
+    fn eval(
+        &self,
+        actual: HeaderValue,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Header> {
+        match predicate {
+            Is => actual.is_eq(self).assert(log_settings),
+            IsNot => actual.is_ne(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header DSL: {predicate}"),
+        }
     }
 }
 
-impl HeaderDsl<HeaderValue> for String {
+impl HeaderDsl<HeaderValue> for Presence {
+    fn eval(
+        &self,
+        actual: HeaderValue,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Header> {
+        let actual = Some(actual);
+        match predicate {
+            Predicate::Exists => actual.is_present().assert(log_settings),
+            Predicate::DoesNotExist => actual.is_absent().assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header DSL: {predicate}"),
+        }
+    }
+
+    fn eval_missing(&self, predicate: Predicate, log_settings: &LogSettings) -> Assertion<Header> {
+        let actual: Option<HeaderValue> = None;
+        match predicate {
+            Predicate::Exists => actual.is_present().assert(log_settings),
+            Predicate::DoesNotExist => actual.is_absent().assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header DSL: {predicate}"),
+        }
+    }
+}
+
+impl HeaderDsl<HeaderValue> for RegexWrapper<&str> {
+    fn eval(
+        &self,
+        actual: HeaderValue,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Header> {
+        match predicate {
+            Matches => self.matches(actual).assert(log_settings),
+            DoesNotMatch => self.does_not_match(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header DSL: {predicate}"),
+        }
+    }
+}
+
+impl HeaderDsl<HeaderValue> for RegexWrapper<String> {
+    fn eval(
+        &self,
+        actual: HeaderValue,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Header> {
+        match predicate {
+            Matches => self.matches(actual).assert(log_settings),
+            DoesNotMatch => self.does_not_match(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header DSL: {predicate}"),
+        }
+    }
+}
+
+/// Http header DSL to assert the header value equality and content.
+pub trait HeaderValueDsl<T>: HeaderDsl<T> {
+    /// Asserts the header is strictly equal to the provided one.
+    fn is(&self, actual: T) -> Assertion<Header>;
+    /// Asserts the header is strictly not equal to the provided one.
+    fn is_not(&self, actual: T) -> Assertion<Header>;
+    /// Asserts the header contains the provided value.
+    fn contains(&self, actual: T) -> Assertion<Header>;
+    /// Asserts the header does not contain the provided value.
+    fn does_not_contain(&self, actual: T) -> Assertion<Header>;
+    /// Asserts the header starts with the provided value.
+    fn starts_with(&self, actual: T) -> Assertion<Header>;
+    /// Asserts the header ends with the provided value.
+    fn ends_with(&self, actual: T) -> Assertion<Header>;
+}
+
+/// Http header regex DSL.
+pub trait HeaderRegexDsl<T>: HeaderDsl<T> {
+    /// Asserts the header matches the regex.
+    fn matches(&self, actual: T) -> Assertion<Header>;
+    /// Asserts the header does not match the regex.
+    fn does_not_match(&self, actual: T) -> Assertion<Header>;
+}
+
+impl HeaderValueDsl<HeaderValue> for &str {
     fn is(&self, actual: HeaderValue) -> Assertion<Header> {
         actual.is_eq(self)
     }
@@ -45,9 +180,25 @@ impl HeaderDsl<HeaderValue> for String {
     fn is_not(&self, actual: HeaderValue) -> Assertion<Header> {
         actual.is_ne(self)
     }
+
+    fn contains(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.has(*self)
+    }
+
+    fn does_not_contain(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.has_not(*self)
+    }
+
+    fn starts_with(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.has_prefix(*self)
+    }
+
+    fn ends_with(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.has_suffix(*self)
+    }
 }
 
-impl HeaderDsl<HeaderValue> for HeaderValue {
+impl HeaderValueDsl<HeaderValue> for String {
     fn is(&self, actual: HeaderValue) -> Assertion<Header> {
         actual.is_eq(self)
     }
@@ -55,4 +206,40 @@ impl HeaderDsl<HeaderValue> for HeaderValue {
     fn is_not(&self, actual: HeaderValue) -> Assertion<Header> {
         actual.is_ne(self)
     }
+
+    fn contains(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.has(self.as_str())
+    }
+
+    fn does_not_contain(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.has_not(self.as_str())
+    }
+
+    fn starts_with(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.has_prefix(self.as_str())
+    }
+
+    fn ends_with(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.has_suffix(self.as_str())
+    }
+}
+
+impl HeaderRegexDsl<HeaderValue> for RegexWrapper<&str> {
+    fn matches(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.is_match(self.0)
+    }
+
+    fn does_not_match(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.is_not_match(self.0)
+    }
+}
+
+impl HeaderRegexDsl<HeaderValue> for RegexWrapper<String> {
+    fn matches(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.is_match(self.0.as_str())
+    }
+
+    fn does_not_match(&self, actual: HeaderValue) -> Assertion<Header> {
+        actual.is_not_match(self.0.as_str())
+    }
 }
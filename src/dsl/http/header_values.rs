@@ -0,0 +1,85 @@
+use http::HeaderValue;
+
+use crate::{
+    assertion::{
+        traits::{Container, Equality},
+        types::HeaderValues,
+        Assertion,
+    },
+    dsl::expression::Predicate::{self, Contains, DoesNotContain, Is, IsNot},
+    LogSettings,
+};
+
+/// Http header values DSL to assert all the values of a header from a
+/// response.
+pub trait HeaderValuesDsl<T> {
+    /// Evaluates the header values assertion to run based on the
+    /// [`Predicate`].
+    fn eval(
+        &self,
+        actual: T,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<HeaderValues>;
+}
+
+impl HeaderValuesDsl<Vec<HeaderValue>> for Vec<&str> {
+    fn eval(
+        &self,
+        actual: Vec<HeaderValue>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<HeaderValues> {
+        let expected: HeaderValues = self.iter().map(|value| value.to_string()).collect();
+        match predicate {
+            Is => actual.is_eq(&expected).assert(log_settings),
+            IsNot => actual.is_ne(&expected).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header values DSL: {predicate}"),
+        }
+    }
+}
+
+impl HeaderValuesDsl<Vec<HeaderValue>> for Vec<String> {
+    fn eval(
+        &self,
+        actual: Vec<HeaderValue>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<HeaderValues> {
+        match predicate {
+            Is => actual.is_eq(self).assert(log_settings),
+            IsNot => actual.is_ne(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header values DSL: {predicate}"),
+        }
+    }
+}
+
+impl HeaderValuesDsl<Vec<HeaderValue>> for &str {
+    fn eval(
+        &self,
+        actual: Vec<HeaderValue>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<HeaderValues> {
+        match predicate {
+            Contains => actual.has(*self).assert(log_settings),
+            DoesNotContain => actual.has_not(*self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header values DSL: {predicate}"),
+        }
+    }
+}
+
+impl HeaderValuesDsl<Vec<HeaderValue>> for String {
+    fn eval(
+        &self,
+        actual: Vec<HeaderValue>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<HeaderValues> {
+        match predicate {
+            Contains => actual.has(self.as_str()).assert(log_settings),
+            DoesNotContain => actual.has_not(self.as_str()).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the header values DSL: {predicate}"),
+        }
+    }
+}
@@ -1,13 +1,14 @@
 use crate::{
     assertion::{
-        traits::{Container, Equality},
+        traits::{Container, Equality, SnapshotMatching},
         types::Headers,
         Assertion,
     },
-    dsl::expression::Predicate,
+    dsl::{expression::Predicate, Snapshot},
     header::{HeaderMap, HeaderName, HeaderValue},
     LogSettings,
 };
+use serde_json::Value;
 
 // TODO: see to use the low-level types
 type HeadersVec = Vec<(HeaderName, HeaderValue)>;
@@ -93,3 +94,26 @@ impl HeadersDsl<HeaderMap> for HeadersStrVec {
         actual.has_not(self)
     }
 }
+
+/// Http headers DSL to assert the headers of the response against a snapshot
+/// file.
+pub trait HeadersSnapshotDsl<T> {
+    /// Evaluates the headers snapshot assertion to run based on the
+    /// [`Predicate`].
+    fn eval(&self, actual: T, predicate: Predicate, log_settings: &LogSettings)
+        -> Assertion<Value>;
+}
+
+impl HeadersSnapshotDsl<HeaderMap> for Snapshot {
+    fn eval(
+        &self,
+        actual: HeaderMap,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Predicate::MatchesSnapshot => actual.matches_snapshot(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the headers snapshot DSL: {predicate}"),
+        }
+    }
+}
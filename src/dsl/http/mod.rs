@@ -30,13 +30,21 @@
 //! }
 
 mod body;
+mod body_bytes;
+mod cookie;
 mod header;
+mod header_values;
 mod headers;
 mod status;
+mod text_body;
 mod time;
 
-pub use self::body::JsonBodyDsl;
-pub use self::header::HeaderDsl;
-pub use self::headers::HeadersDsl;
+pub use self::body::{JsonBodyDsl, JsonBodyValueDsl};
+pub use self::body_bytes::{BodyBytesDsl, BodyBytesRegexDsl, BodyBytesValueDsl};
+pub use self::cookie::{cookie_attributes, Cookie, CookieDsl, SameSite};
+pub use self::header::{HeaderDsl, HeaderRegexDsl, HeaderValueDsl};
+pub use self::header_values::HeaderValuesDsl;
+pub use self::headers::{HeadersDsl, HeadersSnapshotDsl};
 pub use self::status::*;
+pub use self::text_body::{TextBodyDsl, TextBodyRegexDsl, TextBodyValueDsl};
 pub use self::time::TimeDsl;
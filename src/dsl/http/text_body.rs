@@ -0,0 +1,162 @@
+use crate::{
+    assertion::{
+        traits::{Container, Equality, Matching},
+        Assertion,
+    },
+    dsl::{
+        expression::Predicate::{self, Contains, DoesNotContain, DoesNotMatch, Is, IsNot, Matches},
+        RegexWrapper,
+    },
+    LogSettings,
+};
+
+/// Http text body DSL to assert the body of a response as text.
+pub trait TextBodyDsl<T> {
+    /// Evaluates the text body assertion to run based on the [`Predicate`].
+    fn eval(
+        &self,
+        actual: T,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<String>;
+}
+
+impl TextBodyDsl<String> for &str {
+    fn eval(
+        &self,
+        actual: String,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<String> {
+        match predicate {
+            Is => self.is(actual).assert(log_settings),
+            IsNot => self.is_not(actual).assert(log_settings),
+            Contains => self.contains(actual).assert(log_settings),
+            DoesNotContain => self.does_not_contain(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the text body DSL: {predicate}"),
+        }
+    }
+}
+
+impl TextBodyDsl<String> for String {
+    fn eval(
+        &self,
+        actual: String,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<String> {
+        match predicate {
+            Is => self.is(actual).assert(log_settings),
+            IsNot => self.is_not(actual).assert(log_settings),
+            Contains => self.contains(actual).assert(log_settings),
+            DoesNotContain => self.does_not_contain(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the text body DSL: {predicate}"),
+        }
+    }
+}
+
+impl TextBodyDsl<String> for RegexWrapper<&str> {
+    fn eval(
+        &self,
+        actual: String,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<String> {
+        match predicate {
+            Matches => self.matches(actual).assert(log_settings),
+            DoesNotMatch => self.does_not_match(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the text body DSL: {predicate}"),
+        }
+    }
+}
+
+impl TextBodyDsl<String> for RegexWrapper<String> {
+    fn eval(
+        &self,
+        actual: String,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<String> {
+        match predicate {
+            Matches => self.matches(actual).assert(log_settings),
+            DoesNotMatch => self.does_not_match(actual).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the text body DSL: {predicate}"),
+        }
+    }
+}
+
+/// Http text body DSL to assert the text body equality and content.
+pub trait TextBodyValueDsl<T>: TextBodyDsl<T> {
+    /// Asserts that the text body is strictly equal to the provided value.
+    fn is(&self, actual: T) -> Assertion<String>;
+    /// Asserts that the text body is strictly not equal to the provided value.
+    fn is_not(&self, actual: T) -> Assertion<String>;
+    /// Asserts that the text body contains the provided value.
+    fn contains(&self, actual: T) -> Assertion<String>;
+    /// Asserts that the text body does not contain the provided value.
+    fn does_not_contain(&self, actual: T) -> Assertion<String>;
+}
+
+/// Http text body regex DSL.
+pub trait TextBodyRegexDsl<T>: TextBodyDsl<T> {
+    /// Asserts that the text body matches the regex.
+    fn matches(&self, actual: T) -> Assertion<String>;
+    /// Asserts that the text body does not match the regex.
+    fn does_not_match(&self, actual: T) -> Assertion<String>;
+}
+
+impl TextBodyValueDsl<String> for &str {
+    fn is(&self, actual: String) -> Assertion<String> {
+        actual.is_eq(*self)
+    }
+
+    fn is_not(&self, actual: String) -> Assertion<String> {
+        actual.is_ne(*self)
+    }
+
+    fn contains(&self, actual: String) -> Assertion<String> {
+        actual.has(*self)
+    }
+
+    fn does_not_contain(&self, actual: String) -> Assertion<String> {
+        actual.has_not(*self)
+    }
+}
+
+impl TextBodyValueDsl<String> for String {
+    fn is(&self, actual: String) -> Assertion<String> {
+        actual.is_eq(self.as_str())
+    }
+
+    fn is_not(&self, actual: String) -> Assertion<String> {
+        actual.is_ne(self.as_str())
+    }
+
+    fn contains(&self, actual: String) -> Assertion<String> {
+        actual.has(self.as_str())
+    }
+
+    fn does_not_contain(&self, actual: String) -> Assertion<String> {
+        actual.has_not(self.as_str())
+    }
+}
+
+impl TextBodyRegexDsl<String> for RegexWrapper<&str> {
+    fn matches(&self, actual: String) -> Assertion<String> {
+        actual.is_match(self.0)
+    }
+
+    fn does_not_match(&self, actual: String) -> Assertion<String> {
+        actual.is_not_match(self.0)
+    }
+}
+
+impl TextBodyRegexDsl<String> for RegexWrapper<String> {
+    fn matches(&self, actual: String) -> Assertion<String> {
+        actual.is_match(self.0.as_str())
+    }
+
+    fn does_not_match(&self, actual: String) -> Assertion<String> {
+        actual.is_not_match(self.0.as_str())
+    }
+}
@@ -2,11 +2,18 @@
 
 use crate::{
     assertion::{
-        traits::{Container, Equality, JsonSchema, Matching},
+        traits::{
+            ApproxEquality, Container, Equality, Existence, GreaterOrEqual, GreaterThan, HasLength,
+            JsonSchema, LessOrEqual, LessThan, Matching, OfType, RangeInclusive, UnorderedEquality,
+        },
         Assertion,
     },
-    dsl::expression::Predicate::{
-        self, Contains, DoesNotContain, DoesNotMatch, Is, IsNot, Matches, Schema,
+    dsl::expression::{
+        Approx, Expression, JsonEquality, Length,
+        Predicate::{
+            self, Between, Contains, DoesNotContain, DoesNotMatch, Is, IsNot, Matches, Schema,
+        },
+        Presence, Range,
     },
     LogSettings,
 };
@@ -14,7 +21,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::path::PathBuf;
 
-use super::RegexWrapper;
+use super::{JsonType, RegexWrapper};
 
 /// Represents the result of a json path query.
 ///
@@ -36,6 +43,21 @@ impl<'p, T> JsonPathResult<'p, T> {
     }
 }
 
+impl JsonPathResult<'_, Value> {
+    /// Returns `true` if the json path query found at least one node.
+    ///
+    /// The query returns an array of the found nodes, a node explicitly set
+    /// to `null` is returned as `[null]`. When nothing is found, the query
+    /// returns either `null` or an empty array.
+    pub fn found(&self) -> bool {
+        match &self.value {
+            Value::Null => false,
+            Value::Array(nodes) => !nodes.is_empty(),
+            _ => true,
+        }
+    }
+}
+
 /// Json path DSL to assert a value at a given path.
 pub trait JsonPathDsl<T> {
     /// Evaluates the status assertion to run depending on the [`Predicate`].
@@ -62,6 +84,178 @@ impl JsonPathDsl<Value> for Value {
             Schema => self.schema(jsonpath_res).assert(log_settings),
             Contains => self.contains(jsonpath_res).assert(log_settings),
             DoesNotContain => self.does_not_contain(jsonpath_res).assert(log_settings),
+            Predicate::LessThan => self.is_less_than(jsonpath_res).assert(log_settings),
+            Predicate::LessOrEqual => self.is_less_or_equal(jsonpath_res).assert(log_settings),
+            Predicate::GreaterThan => self.is_greater_than(jsonpath_res).assert(log_settings),
+            Predicate::GreaterOrEqual => {
+                self.is_greater_or_equal(jsonpath_res).assert(log_settings)
+            }
+            Predicate::IsUnordered => jsonpath_res.unordered_eq(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
+        }
+    }
+}
+
+/// Implements the json path DSL for the primitive number types by converting
+/// the expected number to a json value.
+macro_rules! impl_json_path_dsl_for_numbers {
+    ($($ty:ty),*) => {
+        $(
+            impl JsonPathDsl<Value> for $ty {
+                fn eval(
+                    &self,
+                    jsonpath_res: JsonPathResult<'_, Value>,
+                    predicate: Predicate,
+                    log_settings: &LogSettings,
+                ) -> Assertion<Value> {
+                    Value::from(*self).eval(jsonpath_res, predicate, log_settings)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_path_dsl_for_numbers!(i32, i64, u32, u64, f32, f64);
+
+impl<T> JsonPathDsl<Value> for Range<T>
+where
+    T: Clone + Into<Value>,
+{
+    fn eval(
+        &self,
+        jsonpath_res: JsonPathResult<'_, Value>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Between => {
+                let min: Value = self.left.clone().into();
+                let max: Value = self.right.clone().into();
+                jsonpath_res.in_range(&min, &max).assert(log_settings)
+            }
+            _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
+        }
+    }
+}
+
+/// Implements the json path DSL for the lengths given as primitive integer
+/// types.
+macro_rules! impl_json_path_dsl_for_lengths {
+    ($($ty:ty),*) => {
+        $(
+            impl JsonPathDsl<Value> for Length<$ty> {
+                fn eval(
+                    &self,
+                    jsonpath_res: JsonPathResult<'_, Value>,
+                    predicate: Predicate,
+                    log_settings: &LogSettings,
+                ) -> Assertion<Value> {
+                    match predicate {
+                        Predicate::HasLength => jsonpath_res
+                            .length_eq(&Value::from(self.0))
+                            .assert(log_settings),
+                        _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_json_path_dsl_for_lengths!(i32, i64, u32, u64, usize);
+
+impl<T> JsonPathDsl<Value> for Length<Expression<Range<T>>>
+where
+    T: Clone + Into<Value>,
+{
+    fn eval(
+        &self,
+        jsonpath_res: JsonPathResult<'_, Value>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match (predicate, &self.0.predicate) {
+            (Predicate::HasLength, Between) => {
+                let min: Value = self.0.value.left.clone().into();
+                let max: Value = self.0.value.right.clone().into();
+                jsonpath_res
+                    .length_in_range(&min, &max)
+                    .assert(log_settings)
+            }
+            (predicate, _) => {
+                unimplemented!("Invalid predicate for the json path DSL: {predicate}")
+            }
+        }
+    }
+}
+
+impl<T: JsonPathValueDsl<Value>> JsonPathDsl<Value> for JsonEquality<T> {
+    fn eval(
+        &self,
+        jsonpath_res: JsonPathResult<'_, Value>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        let assertion = match predicate {
+            Is => self.value.is(jsonpath_res),
+            IsNot => self.value.is_not(jsonpath_res),
+            _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
+        };
+
+        assertion
+            .ignoring(&self.ignored)
+            .within_tolerance(self.tolerance, self.unordered)
+            .in_any_order(self.unordered)
+            .assert(log_settings)
+    }
+}
+
+impl<T> JsonPathDsl<Value> for Approx<T>
+where
+    T: Clone + Into<Value>,
+{
+    fn eval(
+        &self,
+        jsonpath_res: JsonPathResult<'_, Value>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Predicate::IsApprox => {
+                let expected: Value = self.value.clone().into();
+                jsonpath_res
+                    .approx_eq(&expected, self.epsilon)
+                    .assert(log_settings)
+            }
+            _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
+        }
+    }
+}
+
+impl JsonPathDsl<Value> for JsonType {
+    fn eval(
+        &self,
+        jsonpath_res: JsonPathResult<'_, Value>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Predicate::IsType => jsonpath_res.of_type(self).assert(log_settings),
+            _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
+        }
+    }
+}
+
+impl JsonPathDsl<Value> for Presence {
+    fn eval(
+        &self,
+        jsonpath_res: JsonPathResult<'_, Value>,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<Value> {
+        match predicate {
+            Predicate::Exists => jsonpath_res.is_present().assert(log_settings),
+            Predicate::DoesNotExist => jsonpath_res.is_absent().assert(log_settings),
             _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
         }
     }
@@ -80,6 +274,7 @@ impl JsonPathDsl<Value> for String {
             Schema => self.schema(jsonpath_res).assert(log_settings),
             Contains => self.contains(jsonpath_res).assert(log_settings),
             DoesNotContain => self.does_not_contain(jsonpath_res).assert(log_settings),
+            Predicate::IsUnordered => jsonpath_res.unordered_eq(self).assert(log_settings),
             _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
         }
     }
@@ -98,6 +293,7 @@ impl JsonPathDsl<Value> for &str {
             Schema => self.schema(jsonpath_res).assert(log_settings),
             Contains => self.contains(jsonpath_res).assert(log_settings),
             DoesNotContain => self.does_not_contain(jsonpath_res).assert(log_settings),
+            Predicate::IsUnordered => jsonpath_res.unordered_eq(*self).assert(log_settings),
             _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
         }
     }
@@ -116,6 +312,7 @@ impl JsonPathDsl<Value> for PathBuf {
             Schema => self.schema(jsonpath_res).assert(log_settings),
             Contains => self.contains(jsonpath_res).assert(log_settings),
             DoesNotContain => self.does_not_contain(jsonpath_res).assert(log_settings),
+            Predicate::IsUnordered => jsonpath_res.unordered_eq(self).assert(log_settings),
             _ => unimplemented!("Invalid predicate for the json path DSL: {predicate}"),
         }
     }
@@ -186,13 +383,25 @@ pub trait JsonPathRegexDsl<T>: JsonPathDsl<T> {
     }
 }
 
+/// Http json path DSL to compare numbers.
+pub trait JsonPathNumberDsl<T>: JsonPathDsl<T> {
+    /// Asserts that the json path value is less than the provided number.
+    fn is_less_than(&self, jsonpath_res: JsonPathResult<'_, T>) -> Assertion<Value>;
+    /// Asserts that the json path value is less than or equal to the provided number.
+    fn is_less_or_equal(&self, jsonpath_res: JsonPathResult<'_, T>) -> Assertion<Value>;
+    /// Asserts that the json path value is greater than the provided number.
+    fn is_greater_than(&self, jsonpath_res: JsonPathResult<'_, T>) -> Assertion<Value>;
+    /// Asserts that the json path value is greater than or equal to the provided number.
+    fn is_greater_or_equal(&self, jsonpath_res: JsonPathResult<'_, T>) -> Assertion<Value>;
+}
+
 impl JsonPathValueDsl<Value> for Value {
     fn is(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
-        jsonpath_res.is_eq(self)
+        jsonpath_res.is_eq(self).with_placeholders()
     }
 
     fn is_not(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
-        jsonpath_res.is_ne(self)
+        jsonpath_res.is_ne(self).with_placeholders()
     }
 
     fn schema(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
@@ -208,13 +417,31 @@ impl JsonPathValueDsl<Value> for Value {
     }
 }
 
+impl JsonPathNumberDsl<Value> for Value {
+    fn is_less_than(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
+        jsonpath_res.less_than(self)
+    }
+
+    fn is_less_or_equal(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
+        jsonpath_res.less_or_equal(self)
+    }
+
+    fn is_greater_than(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
+        jsonpath_res.greater_than(self)
+    }
+
+    fn is_greater_or_equal(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
+        jsonpath_res.greater_or_equal(self)
+    }
+}
+
 impl JsonPathValueDsl<Value> for String {
     fn is(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
-        jsonpath_res.is_eq(self)
+        jsonpath_res.is_eq(self).with_placeholders()
     }
 
     fn is_not(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
-        jsonpath_res.is_ne(self)
+        jsonpath_res.is_ne(self).with_placeholders()
     }
 
     fn schema(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
@@ -232,11 +459,11 @@ impl JsonPathValueDsl<Value> for String {
 
 impl JsonPathValueDsl<Value> for &str {
     fn is(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
-        jsonpath_res.is_eq(*self)
+        jsonpath_res.is_eq(*self).with_placeholders()
     }
 
     fn is_not(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
-        jsonpath_res.is_ne(*self)
+        jsonpath_res.is_ne(*self).with_placeholders()
     }
 
     fn schema(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
@@ -254,11 +481,11 @@ impl JsonPathValueDsl<Value> for &str {
 
 impl JsonPathValueDsl<Value> for PathBuf {
     fn is(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
-        jsonpath_res.is_eq(self)
+        jsonpath_res.is_eq(self).with_placeholders()
     }
 
     fn is_not(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
-        jsonpath_res.is_ne(self)
+        jsonpath_res.is_ne(self).with_placeholders()
     }
 
     fn schema(&self, jsonpath_res: JsonPathResult<'_, Value>) -> Assertion<Value> {
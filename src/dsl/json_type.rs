@@ -0,0 +1,89 @@
+//! Module containing the json types a value can be checked against.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::Display;
+
+/// Represents the types of a json value. Provides a string representation for
+/// each variant to build assertion messages in a convenient way.
+///
+/// An [`Integer`] is also a [`Number`]: a number without a fractional part,
+/// like `2` or `2.0`, is of both types.
+///
+/// [`Integer`]: JsonType::Integer
+/// [`Number`]: JsonType::Number
+#[derive(Display, Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum JsonType {
+    /// A json string.
+    String,
+    /// A json number, integer or not.
+    Number,
+    /// A json number without a fractional part.
+    Integer,
+    /// A json boolean.
+    Bool,
+    /// A json array.
+    Array,
+    /// A json object.
+    Object,
+    /// The json `null` value.
+    Null,
+}
+
+impl JsonType {
+    /// Returns the most specific type of the given value: an integer number
+    /// is of type [`JsonType::Integer`].
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::String(_) => JsonType::String,
+            Value::Number(number) if is_integer(number) => JsonType::Integer,
+            Value::Number(_) => JsonType::Number,
+            Value::Bool(_) => JsonType::Bool,
+            Value::Array(_) => JsonType::Array,
+            Value::Object(_) => JsonType::Object,
+            Value::Null => JsonType::Null,
+        }
+    }
+
+    /// Returns `true` if the given value is of this type.
+    pub fn matches(&self, value: &Value) -> bool {
+        match (self, JsonType::of(value)) {
+            (JsonType::Number, JsonType::Integer) => true,
+            (expected, actual) => *expected == actual,
+        }
+    }
+}
+
+fn is_integer(number: &serde_json::Number) -> bool {
+    number.is_i64() || number.is_u64() || number.as_f64().is_some_and(|n| n.fract() == 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonType;
+    use serde_json::json;
+    use test_case::test_case;
+
+    #[test_case(json!("12"), JsonType::String)]
+    #[test_case(json!(12), JsonType::Integer)]
+    #[test_case(json!(-12), JsonType::Integer)]
+    #[test_case(json!(12.0), JsonType::Integer)]
+    #[test_case(json!(12.5), JsonType::Number)]
+    #[test_case(json!(true), JsonType::Bool)]
+    #[test_case(json!([12]), JsonType::Array)]
+    #[test_case(json!({ "id": 12 }), JsonType::Object)]
+    #[test_case(json!(null), JsonType::Null)]
+    fn it_should_find_the_type_of_a_value(value: serde_json::Value, json_type: JsonType) {
+        assert_eq!(JsonType::of(&value), json_type);
+        assert!(json_type.matches(&value));
+    }
+
+    #[test]
+    fn an_integer_should_be_a_number() {
+        assert!(JsonType::Number.matches(&json!(12)));
+        assert!(!JsonType::Integer.matches(&json!(12.5)));
+        assert!(!JsonType::String.matches(&json!(12)));
+    }
+}
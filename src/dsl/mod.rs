@@ -5,7 +5,9 @@ mod expression;
 #[allow(clippy::wrong_self_convention)]
 pub mod http;
 pub mod json_path;
+mod json_type;
 mod part;
 
 pub use self::expression::*;
+pub use self::json_type::JsonType;
 pub use self::part::Part;
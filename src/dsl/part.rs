@@ -7,12 +7,20 @@ use strum::Display;
 /// Represents all the parts we can assert against. Provides a string
 /// representation for each variant to build assertion messages in a convenient
 /// way.
-#[derive(Display, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Display, Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
 pub enum Part {
     /// The json body of an http response.
     #[strum(serialize = "json body")]
     #[serde(rename = "json body")]
     JsonBody,
+    /// The text body of an http response.
+    #[strum(serialize = "text body")]
+    #[serde(rename = "text body")]
+    TextBody,
+    /// The raw body of an http response.
+    #[strum(serialize = "body bytes")]
+    #[serde(rename = "body bytes")]
+    BodyBytes,
     /// The json value of an http response at the given path.
     #[strum(serialize = "json path")]
     #[serde(rename = "json path")]
@@ -25,6 +33,10 @@ pub enum Part {
     #[strum(serialize = "header")]
     #[serde(rename = "header")]
     Header,
+    /// A cookie set by an http response.
+    #[strum(serialize = "cookie")]
+    #[serde(rename = "cookie")]
+    Cookie,
     /// The status code of an http response.
     #[strum(serialize = "status code")]
     #[serde(rename = "status code")]
@@ -49,9 +61,12 @@ pub mod tests {
     #[test_case(Value::String(String::from("json body")), Part::JsonBody; "Failed to deserialize part JsonBody")]
     #[test_case(Value::String(String::from("headers")), Part::Headers; "Failed to deserialize part Headers")]
     #[test_case(Value::String(String::from("header")), Part::Header; "Failed to deserialize part Header")]
+    #[test_case(Value::String(String::from("cookie")), Part::Cookie; "Failed to deserialize part Cookie")]
     #[test_case(Value::String(String::from("status code")), Part::StatusCode; "Failed to deserialize part StatusCode")]
     #[test_case(Value::String(String::from("response time")), Part::ResponseTime; "Failed to deserialize part ResponseTime")]
     #[test_case(Value::String(String::from("json path")), Part::JsonPath; "Failed to deserialize part JsonPath")]
+    #[test_case(Value::String(String::from("text body")), Part::TextBody; "Failed to deserialize part TextBody")]
+    #[test_case(Value::String(String::from("body bytes")), Part::BodyBytes; "Failed to deserialize part BodyBytes")]
     fn deser_part(json_part: Value, part: Part) {
         assert_eq!(serde_json::from_value::<Part>(json_part).unwrap(), part)
     }
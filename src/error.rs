@@ -1,3 +1,5 @@
+use crate::report::ReportEntry;
+
 /// Short hand for `Result` type.
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -16,4 +18,32 @@ pub enum Error {
     /// Invalid header value.
     #[error("Invalid header value")]
     InvalidHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
+    /// Query parameters that cannot be url encoded.
+    #[error("Invalid query parameters: {0}")]
+    InvalidQueryParameters(#[from] serde_urlencoded::ser::Error),
+    /// Form body that cannot be url encoded.
+    #[error("Invalid form body: {0}")]
+    InvalidFormBody(serde_urlencoded::ser::Error),
+    /// File that cannot be read, with the path of the file.
+    #[error("Failed to read file located at {0}: {1}")]
+    FileReadError(String, #[source] std::io::Error),
+    /// File that cannot be written, with the path of the file.
+    #[error("Failed to write file located at {0}: {1}")]
+    FileWriteError(String, #[source] std::io::Error),
+    /// Cookie that cannot be added to the cookie jar, with the reason.
+    #[error("Invalid cookie {0}: {1}")]
+    InvalidCookie(String, String),
+    /// Cookie jar file that cannot be saved or loaded, with the path of the
+    /// file.
+    #[error("Invalid cookie jar file located at {0}: {1}")]
+    InvalidCookieJar(String, String),
+    /// Failed or unprocessable assertions collected by an [`Assert`].
+    ///
+    /// [`Assert`]: crate::Assert
+    #[error(
+        "{} assertion(s) failed:\n\n{}",
+        .0.len(),
+        .0.iter().map(|entry| entry.message.as_str()).collect::<Vec<_>>().join("\n\n")
+    )]
+    AssertionsFailed(Vec<ReportEntry>),
 }
@@ -1,7 +1,13 @@
+use crate::context::Context;
+use crate::cookie_jar::CookieJar;
 use crate::error::Result;
+use crate::request::{BasicAuth, BearerToken, RequestHeaders};
 use crate::Request;
-use http::{HeaderMap, Method};
+use http::{header::USER_AGENT, HeaderMap, HeaderValue, Method};
 use reqwest::{Client, ClientBuilder};
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Duration;
 use url::Url;
 
 /// Top-level instance to configure a REST API http client.
@@ -12,27 +18,36 @@ pub struct Grillon {
     base_url: Url,
     client: Client,
     log_settings: LogSettings,
+    default_headers: HeaderMap,
+    default_basic_auth: Option<BasicAuth>,
+    default_bearer_auth: Option<BearerToken>,
+    timeout: Option<Duration>,
+    user_agent: Option<HeaderValue>,
+    context: Context,
+    cookie_jar: CookieJar,
 }
 
 /// The log settings to output test results.
 ///
 /// The default configuration is `StdAssert`.
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub enum LogSettings {
     /// Only prints assertion failures through `std::assert` macro.
+    #[default]
     StdAssert,
+    /// Evaluates every assertion and prints the failures to the standard
+    /// output. A single panic listing all the failures happens once the
+    /// [`Assert`] is dropped or finished with [`Assert::finish`].
+    ///
+    /// [`Assert`]: crate::Assert
+    /// [`Assert::finish`]: crate::Assert::finish
+    SoftAssert,
     /// Prints all assertion results to the standard output.
     StdOutput,
     /// Formats assertion results into a json output.
     JsonOutput,
 }
 
-impl Default for LogSettings {
-    fn default() -> Self {
-        Self::StdAssert
-    }
-}
-
 impl Grillon {
     /// Creates a new instance of `Grillon` with the base API url.
     ///
@@ -56,6 +71,13 @@ impl Grillon {
             base_url: base_url.parse::<Url>()?,
             client,
             log_settings: LogSettings::default(),
+            default_headers: HeaderMap::new(),
+            default_basic_auth: None,
+            default_bearer_auth: None,
+            timeout: None,
+            user_agent: None,
+            context: Context::default(),
+            cookie_jar: CookieJar::default(),
         })
     }
 
@@ -71,14 +93,151 @@ impl Grillon {
 
     /// Enable a persistent cookie store for the client. By default,
     /// no cookie store is used. Enabling the cookie store with `store_cookies()`
-    /// will update the http client and set the store to a default implementation.
+    /// will update the http client to store the cookies in the [`CookieJar`].
+    ///
+    /// The http client is given a clone of the cookie jar, which is a handle
+    /// to the same store: the cookies set by the responses are visible through
+    /// [`Grillon::cookie_jar`], and the cookies added or loaded there are sent
+    /// with the requests, before or after the store is enabled. Disabling the
+    /// store keeps the cookies of the jar, they are just no longer sent.
     pub fn store_cookies(mut self, enable: bool) -> Result<Grillon> {
-        let client = ClientBuilder::new().cookie_store(enable).build()?;
+        let client = if enable {
+            ClientBuilder::new()
+                .cookie_provider(Arc::new(self.cookie_jar.clone()))
+                .build()?
+        } else {
+            ClientBuilder::new().cookie_store(false).build()?
+        };
         self.client = client;
 
         Ok(self)
     }
 
+    /// Sets the headers sent with every [`Request`].
+    ///
+    /// A header set on a request with [`Request::headers`] replaces all the
+    /// default values of the same header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result, header::{ACCEPT, HeaderValue}};
+    /// # fn run() -> Result<()> {
+    /// let grillon = Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .default_headers(vec![(ACCEPT, HeaderValue::from_static("application/json"))])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the headers cannot be converted to a
+    /// [`HeaderMap`].
+    pub fn default_headers<H: RequestHeaders>(mut self, headers: H) -> Result<Grillon> {
+        self.default_headers = headers.to_header_map()?;
+
+        Ok(self)
+    }
+
+    /// Enable HTTP basic authentication for every [`Request`].
+    ///
+    /// It replaces the default bearer authentication and can be overridden
+    /// per request with [`Request::basic_auth`] or [`Request::bearer_auth`].
+    pub fn default_basic_auth<U, P>(mut self, username: U, password: Option<P>) -> Self
+    where
+        U: AsRef<str> + Display,
+        P: AsRef<str> + Display,
+    {
+        self.default_basic_auth = Some(BasicAuth {
+            username: username.to_string(),
+            password: password.map(|pwd| pwd.to_string()),
+        });
+        self.default_bearer_auth = None;
+
+        self
+    }
+
+    /// Enable HTTP bearer authentication for every [`Request`].
+    ///
+    /// It replaces the default basic authentication and can be overridden
+    /// per request with [`Request::basic_auth`] or [`Request::bearer_auth`].
+    pub fn default_bearer_auth<T>(mut self, token: T) -> Self
+    where
+        T: AsRef<str> + Display,
+    {
+        self.default_bearer_auth = Some(BearerToken(token.to_string()));
+        self.default_basic_auth = None;
+
+        self
+    }
+
+    /// Sets the total timeout of every [`Request`], from the moment the
+    /// request is sent until the response body has been received.
+    ///
+    /// A request exceeding the timeout, while waiting for the response or
+    /// while receiving its body, is reported as an unprocessable assertion
+    /// with [`UnprocessableReason::Timeout`]. It can be overridden per request
+    /// with [`Request::timeout`].
+    ///
+    /// [`UnprocessableReason::Timeout`]: crate::assertion::UnprocessableReason::Timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the `User-Agent` header of every [`Request`]. It takes precedence
+    /// over a `User-Agent` in the default headers.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if the user agent is not a valid header value.
+    pub fn user_agent<V: AsRef<str>>(mut self, user_agent: V) -> Result<Grillon> {
+        self.user_agent = Some(HeaderValue::from_str(user_agent.as_ref())?);
+
+        Ok(self)
+    }
+
+    /// Returns the [`Context`] holding the values captured from the responses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result, json};
+    /// # fn run() -> Result<()> {
+    /// let grillon = Grillon::new("https://jsonplaceholder.typicode.com")?;
+    /// grillon.context().set("user_id", json!(1));
+    ///
+    /// let request = grillon.get("users/{{user_id}}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Returns the [`CookieJar`] holding the cookies of the client. The
+    /// cookies are only stored and sent once the cookie store is enabled with
+    /// [`Grillon::store_cookies`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # fn run() -> Result<()> {
+    /// let grillon = Grillon::new("https://jsonplaceholder.typicode.com")?.store_cookies(true)?;
+    /// grillon
+    ///     .cookie_jar()
+    ///     .add("session=abc123; Path=/", "https://jsonplaceholder.typicode.com")?;
+    ///
+    /// let cookies = grillon.cookie_jar().cookies("https://jsonplaceholder.typicode.com/users")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cookie_jar(&self) -> &CookieJar {
+        &self.cookie_jar
+    }
+
     /// Creates a new [`Request`] initialized with a `GET` method and the given path.
     ///
     /// # Example
@@ -218,6 +377,10 @@ impl Grillon {
 
     /// Create a new [`Request`] initialized with the given method and path.
     ///
+    /// The request inherits the default headers, authentication and timeout
+    /// of the client. The `{{name}}` placeholders of the path are replaced by
+    /// the values of the [`Context`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -229,17 +392,29 @@ impl Grillon {
     /// # }
     /// ```
     pub fn http_request(&self, method: Method, path: &str) -> Request<'_> {
-        let url = crate::url::concat(&self.base_url, path).unwrap_or_else(|err| panic!("{}", err));
+        let path = self.context.interpolate(path);
+        let url = crate::url::concat(&self.base_url, &path).unwrap_or_else(|err| panic!("{}", err));
+
+        let mut default_headers = self.default_headers.clone();
+        if let Some(user_agent) = &self.user_agent {
+            default_headers.insert(USER_AGENT, user_agent.clone());
+        }
 
         Request {
             method,
             url,
+            query_error: None,
             headers: Ok(HeaderMap::new()),
+            default_headers,
             payload: None,
+            payload_error: None,
+            content_type: None,
             client: &self.client,
             log_settings: &self.log_settings,
-            basic_auth: None,
-            bearer_auth: None,
+            context: &self.context,
+            basic_auth: self.default_basic_auth.clone(),
+            bearer_auth: self.default_bearer_auth.clone(),
+            timeout: self.timeout,
         }
     }
 }
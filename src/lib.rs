@@ -13,9 +13,12 @@
 
 pub mod assert;
 pub mod assertion;
+pub mod context;
+pub mod cookie_jar;
 pub mod dsl;
 mod error;
 mod grillon;
+pub mod report;
 pub mod request;
 pub mod response;
 mod url;
@@ -23,9 +26,12 @@ mod url;
 #[doc(inline)]
 pub use self::{
     assert::Assert,
+    context::Context,
+    cookie_jar::CookieJar,
     error::{Error, Result},
     grillon::{Grillon, LogSettings},
-    request::Request,
+    report::Report,
+    request::{Multipart, Request},
     response::Response,
 };
 
@@ -0,0 +1,129 @@
+//! The `report` module provides a serializable summary of the assertions
+//! evaluated by an [`Assert`] instance.
+//!
+//! [`Assert`]: crate::Assert
+use crate::assertion::{diff::JsonDiff, Assertion, AssertionResult};
+use crate::dsl::{Part, Predicate};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Debug;
+
+/// The http request whose response is asserted.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RequestInfo {
+    /// The http request method.
+    pub method: String,
+    /// The http request url, including the query parameters.
+    pub url: String,
+}
+
+/// A record of an evaluated [`Assertion`], independent of the type of its
+/// hands.
+#[derive(Serialize, Debug, Clone)]
+pub struct ReportEntry {
+    /// The part under test.
+    pub part: Part,
+    /// The predicate applied in the test.
+    pub predicate: Predicate,
+    /// The json representation of the left hand of the assertion.
+    pub left: Value,
+    /// The json representation of the right hand of the assertion.
+    pub right: Value,
+    /// The assertion result.
+    pub result: AssertionResult,
+    /// The assertion log message.
+    pub message: String,
+    /// The json differences of a failed equality test on the json body or a
+    /// json path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<JsonDiff>,
+    /// The http request whose response is asserted, missing when the
+    /// [`Assert`] isn't created from a [`Request`].
+    ///
+    /// [`Assert`]: crate::Assert
+    /// [`Request`]: crate::Request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<RequestInfo>,
+}
+
+impl<T> From<&Assertion<T>> for ReportEntry
+where
+    T: Debug + Serialize + 'static,
+{
+    fn from(assertion: &Assertion<T>) -> Self {
+        Self {
+            part: assertion.part.clone(),
+            predicate: assertion.predicate.clone(),
+            left: serde_json::to_value(&assertion.left).unwrap_or_default(),
+            right: serde_json::to_value(&assertion.right).unwrap_or_default(),
+            result: assertion.result.clone(),
+            message: assertion.log(),
+            diff: assertion.diff(),
+            request: None,
+        }
+    }
+}
+
+impl ReportEntry {
+    /// Returns a one line description of the entry, naming the part under
+    /// test and the reason when the assertion is unprocessable.
+    pub(crate) fn summary(&self) -> String {
+        let part = match (&self.part, &self.left) {
+            (Part::JsonPath, Value::Array(hand)) => match hand.first() {
+                Some(Value::String(path)) => format!("{} '{path}'", self.part),
+                _ => self.part.to_string(),
+            },
+            (Part::NoPart, _) => "request".to_string(),
+            (part, _) => part.to_string(),
+        };
+
+        match self.result {
+            AssertionResult::Unprocessable(_) => format!("{part}: {}", self.message),
+            _ => part,
+        }
+    }
+}
+
+/// The summary of the assertions evaluated by an [`Assert`] instance, grouped
+/// by result and kept in evaluation order.
+///
+/// [`Assert`]: crate::Assert
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct Report {
+    /// The assertions that passed.
+    pub passed: Vec<ReportEntry>,
+    /// The assertions that failed.
+    pub failed: Vec<ReportEntry>,
+    /// The assertions that could not be processed.
+    pub unprocessable: Vec<ReportEntry>,
+}
+
+impl Report {
+    /// Returns `true` if no assertion failed or was unprocessable.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && self.unprocessable.is_empty()
+    }
+
+    /// Returns the failed and unprocessable assertions, in this order.
+    pub fn failures(&self) -> impl Iterator<Item = &ReportEntry> {
+        self.failed.iter().chain(self.unprocessable.iter())
+    }
+}
+
+impl<'a> FromIterator<&'a ReportEntry> for Report {
+    fn from_iter<I: IntoIterator<Item = &'a ReportEntry>>(entries: I) -> Self {
+        let mut report = Report::default();
+
+        for entry in entries {
+            let group = match entry.result {
+                AssertionResult::Passed => &mut report.passed,
+                AssertionResult::Failed => &mut report.failed,
+                AssertionResult::Unprocessable(_) => &mut report.unprocessable,
+                AssertionResult::NotYetStarted => continue,
+            };
+            group.push(entry.clone());
+        }
+
+        report
+    }
+}
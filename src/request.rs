@@ -2,15 +2,25 @@
 //! for endpoints under tests.
 //!
 //! Currently powered by the [`Reqwest`](https://github.com/seanmonstar/reqwest) HTTP client.
+use std::borrow::Cow;
 use std::fmt::Display;
-use std::{str::FromStr, time::Instant};
+use std::path::Path;
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
 use crate::assertion::{Assertion, AssertionResult, Hand, UnprocessableReason};
+use crate::context::Context;
 use crate::dsl::{Part, Predicate};
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::report::RequestInfo;
 use crate::{assert::Assert, grillon::LogSettings};
-use http::{HeaderMap, HeaderName, HeaderValue, Method};
+use bytes::Bytes;
+use http::{header::CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, Method};
+use reqwest::multipart::{Form, Part as FormPart};
 use reqwest::{Body, Client};
+use serde::Serialize;
 use serde_json::Value;
 use url::Url;
 
@@ -26,15 +36,17 @@ const METHODS_NO_BODY: &[Method] = &[
 /// Represents the basic authentication information for a [`Request`].
 ///
 /// [`Request`]: crate::Request
+#[derive(Clone)]
 pub struct BasicAuth {
-    username: String,
-    password: Option<String>,
+    pub(crate) username: String,
+    pub(crate) password: Option<String>,
 }
 
 /// Represents the bearer authentication information for a [`Request`].
 ///
 /// [`Request`]: crate::Request
-pub struct BearerToken(String);
+#[derive(Clone)]
+pub struct BearerToken(pub(crate) String);
 
 /// A generic http request headers representation.
 ///
@@ -79,32 +91,165 @@ impl RequestHeaders for HeaderMap {
     }
 }
 
+/// A `multipart/form-data` body for a [`Request`], composed of text fields
+/// and file parts.
+///
+/// Errors occurring while building the parts, like a file that cannot be
+/// read, are reported when the request is asserted.
+///
+/// # Example
+///
+/// ```rust
+/// # use grillon::{Grillon, Multipart, Result};
+/// # fn run() -> Result<()> {
+/// let form = Multipart::new()
+///     .text("name", "Isaac")
+///     .bytes("avatar", "avatar.png", b"\x89PNG\r\n\x1a\n".to_vec());
+///
+/// Grillon::new("https://jsonplaceholder.typicode.com")?
+///     .post("users")
+///     .multipart(form);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Multipart {
+    form: Result<Form>,
+}
+
+impl Default for Multipart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Multipart {
+    /// Creates an empty multipart form.
+    pub fn new() -> Self {
+        Self {
+            form: Ok(Form::new()),
+        }
+    }
+
+    /// Adds a text field to the form.
+    pub fn text<N, V>(self, name: N, value: V) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        self.part(name, Ok(FormPart::text(value)))
+    }
+
+    /// Adds a file part from raw bytes to the form.
+    ///
+    /// The content type of the part is guessed from the extension of the
+    /// file name and defaults to `application/octet-stream`.
+    pub fn bytes<N, F, B>(self, name: N, file_name: F, bytes: B) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        F: Into<Cow<'static, str>>,
+        B: Into<Cow<'static, [u8]>>,
+    {
+        let file_name = file_name.into();
+        let mime = mime_guess::from_path(file_name.as_ref()).first_or_octet_stream();
+        let part = FormPart::bytes(bytes)
+            .file_name(file_name)
+            .mime_str(mime.essence_str())
+            .map_err(Error::from);
+
+        self.part(name, part)
+    }
+
+    /// Adds a file part read from disk to the form.
+    ///
+    /// The file name of the part is the one of the path and the content type
+    /// is guessed from its extension.
+    pub fn file<N, P>(self, name: N, path: P) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                return Self {
+                    form: Err(Error::FileReadError(path.display().to_string(), err)),
+                }
+            }
+        };
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        self.bytes(name, file_name, bytes)
+    }
+
+    fn part<N>(self, name: N, part: Result<FormPart>) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        let form = match (self.form, part) {
+            (Ok(form), Ok(part)) => Ok(form.part(name, part)),
+            (Err(err), _) | (_, Err(err)) => Err(err),
+        };
+
+        Self { form }
+    }
+}
+
 /// Represents an outgoing http request.
 ///
 /// Can be executed with [`Request::assert()`].
 pub struct Request<'c> {
     /// The http request method.
     pub method: Method,
-    /// The http request url.
+    /// The http request url, including the query parameters.
     pub url: Url,
+    /// The error of the query parameters that cannot be url encoded, reported
+    /// when the request is asserted.
+    pub(crate) query_error: Option<Error>,
     /// The http request headers.
     pub headers: Result<HeaderMap>,
+    /// The headers inherited from the [`Grillon`] client. A header with the
+    /// same name in the request headers replaces the inherited one.
+    ///
+    /// [`Grillon`]: crate::Grillon
+    pub default_headers: HeaderMap,
     /// The http request payload.
     pub payload: Option<Body>,
+    /// The error of the last body that cannot be built, reported when the
+    /// request is asserted.
+    pub(crate) payload_error: Option<Error>,
+    /// The content type of the payload. It replaces a default content type
+    /// but not the one set in the request headers.
+    pub content_type: Option<HeaderValue>,
     /// The client used for this outgoing request.
     pub client: &'c Client,
     /// The log settings that will be used to output test results
     /// when asserting the http response.
     pub log_settings: &'c LogSettings,
+    /// The context used to interpolate the request and to store the values
+    /// captured from the response.
+    pub context: &'c Context,
     /// Basic authenthication information.
     pub basic_auth: Option<BasicAuth>,
     /// Bearer authentication token.
     pub bearer_auth: Option<BearerToken>,
+    /// The total timeout of the request.
+    pub timeout: Option<Duration>,
 }
 
 impl Request<'_> {
     /// Sets the headers to the [`Request`].
     ///
+    /// The headers are merged with the default headers of the [`Grillon`]
+    /// client, a header set on the request replacing all the default values
+    /// of the same header. The `{{name}}` placeholders of the header values
+    /// are replaced by the values of the [`Context`].
+    ///
+    /// [`Grillon`]: crate::Grillon
+    ///
     /// # Example
     ///
     /// ```rust
@@ -120,13 +265,95 @@ impl Request<'_> {
     /// # }
     /// ```
     pub fn headers<H: RequestHeaders>(mut self, headers: H) -> Self {
-        self.headers = headers.to_header_map();
+        let context = self.context;
+        self.headers = headers.to_header_map().and_then(|headers| {
+            let mut interpolated = HeaderMap::with_capacity(headers.len());
+            for (name, value) in &headers {
+                let value = match value.to_str() {
+                    Ok(text) if text.contains("{{") => {
+                        HeaderValue::from_str(&context.interpolate(text))?
+                    }
+                    _ => value.clone(),
+                };
+                interpolated.append(name, value);
+            }
+
+            Ok(interpolated)
+        });
+
+        self
+    }
+
+    /// Appends the query parameters to the url of the [`Request`].
+    ///
+    /// The parameters can be any type serializable as url encoded pairs, like
+    /// a struct, a map or a sequence of tuples. Keys are appended as-is, so
+    /// repeating a key adds another value for the same parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .get("comments")
+    ///     .query([("postId", "1"), ("postId", "2")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query<Q: Serialize>(mut self, query: Q) -> Self {
+        let url = self.url.clone();
+        let result = {
+            let mut pairs = self.url.query_pairs_mut();
+            let serializer = serde_urlencoded::Serializer::new(&mut pairs);
+            query.serialize(serializer).map(|_| ())
+        };
+
+        if let Some("") = self.url.query() {
+            self.url.set_query(None);
+        }
+
+        // The url is left untouched by parameters that cannot be encoded.
+        if let Err(err) = result {
+            self.url = url;
+            self.query_error.get_or_insert(err.into());
+        }
+
+        self
+    }
+
+    /// Appends a single query parameter to the url of the [`Request`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .get("posts")
+    ///     .query_param("userId", 1)
+    ///     .query_param("title", "qui est esse");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_param<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: AsRef<str>,
+        V: Display,
+    {
+        self.url
+            .query_pairs_mut()
+            .append_pair(key.as_ref(), &value.to_string());
 
         self
     }
 
     /// Sets the body to the [`Request`].
     ///
+    /// The `{{name}}` placeholders of the json strings are replaced by the
+    /// values of the [`Context`]. A string made of a single placeholder is
+    /// replaced by the captured value, keeping its json type.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -141,6 +368,190 @@ impl Request<'_> {
     /// # }
     /// ```
     pub fn payload(mut self, json: Value) -> Self {
+        if !self.accepts_body() {
+            return self;
+        }
+
+        let json = self.context.interpolate_json(json);
+        self.set_payload(Ok(Body::from(json.to_string())));
+
+        self
+    }
+
+    /// Sets a raw body to the [`Request`] with the given content type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .post("users")
+    ///     .body_bytes("<user><name>Isaac</name></user>", "application/xml");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body_bytes<B, C>(mut self, bytes: B, content_type: C) -> Self
+    where
+        B: Into<Bytes>,
+        C: AsRef<str>,
+    {
+        if !self.accepts_body() {
+            return self;
+        }
+
+        match HeaderValue::from_str(content_type.as_ref()) {
+            Ok(content_type) => {
+                self.set_payload(Ok(Body::from(bytes.into())));
+                self.content_type = Some(content_type);
+            }
+            Err(err) => self.set_payload(Err(err.into())),
+        }
+
+        self
+    }
+
+    /// Sets a `text/plain` body to the [`Request`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .post("comments")
+    ///     .body_text("Nice post!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body_text<T: Into<String>>(self, text: T) -> Self {
+        self.body_bytes(text.into(), "text/plain; charset=utf-8")
+    }
+
+    /// Sets the content of a file as the body of the [`Request`].
+    ///
+    /// The file is streamed when the request is sent rather than loaded in
+    /// memory. The content type is guessed from the file extension and
+    /// defaults to `application/octet-stream`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # use std::path::PathBuf;
+    /// # fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .post("posts")
+    ///     .body_file(PathBuf::from("fixtures/post.json"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        if !self.accepts_body() {
+            return self;
+        }
+
+        let path = path.as_ref();
+        let file = match std::fs::File::open(path) {
+            Ok(file) => file,
+            Err(err) => {
+                self.set_payload(Err(Error::FileReadError(path.display().to_string(), err)));
+                return self;
+            }
+        };
+        let mime = mime_guess::from_path(path).first_or_octet_stream();
+
+        self.set_payload(Ok(Body::from(tokio::fs::File::from_std(file))));
+        self.content_type = HeaderValue::from_str(mime.essence_str()).ok();
+
+        self
+    }
+
+    /// Sets an `application/x-www-form-urlencoded` body to the [`Request`].
+    ///
+    /// The form can be any type serializable as url encoded pairs, like a
+    /// struct, a map or a sequence of tuples.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .post("users")
+    ///     .form([("name", "Isaac"), ("city", "Paris")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn form<F: Serialize>(mut self, form: F) -> Self {
+        if !self.accepts_body() {
+            return self;
+        }
+
+        self.set_payload(
+            serde_urlencoded::to_string(form)
+                .map(Body::from)
+                .map_err(Error::InvalidFormBody),
+        );
+        self.content_type = Some(HeaderValue::from_static(
+            "application/x-www-form-urlencoded",
+        ));
+
+        self
+    }
+
+    /// Sets a `multipart/form-data` body to the [`Request`].
+    ///
+    /// See [`Multipart`] to build the form.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Multipart, Result};
+    /// # fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .post("users")
+    ///     .multipart(Multipart::new().text("name", "Isaac"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn multipart(mut self, multipart: Multipart) -> Self {
+        if !self.accepts_body() {
+            return self;
+        }
+
+        let form = match multipart.form {
+            Ok(form) => form,
+            Err(err) => {
+                self.set_payload(Err(err));
+                return self;
+            }
+        };
+
+        let content_type = format!("multipart/form-data; boundary={}", form.boundary());
+        self.content_type = HeaderValue::from_str(&content_type).ok();
+        self.set_payload(Ok(Body::wrap_stream(form.into_stream())));
+
+        self
+    }
+
+    /// Replaces the payload, an invalid body being reported when the request
+    /// is asserted.
+    fn set_payload(&mut self, payload: Result<Body>) {
+        match payload {
+            Ok(body) => {
+                self.payload = Some(body);
+                self.payload_error = None;
+            }
+            Err(err) => {
+                self.payload = None;
+                self.payload_error = Some(err);
+            }
+        }
+    }
+
+    /// Returns `true` if the method of the [`Request`] supports a body.
+    fn accepts_body(&self) -> bool {
         // TODO: See to manage this as an error to collect. To avoid confusion
         // for users we warn them without failing since it might be intended.
         // We can maybe find a better way to manage this case.
@@ -150,18 +561,19 @@ impl Request<'_> {
                 self.method
             );
 
-            return self;
+            return false;
         }
 
-        self.payload = Some(Body::from(json.to_string()));
-
-        self
+        true
     }
 
     /// Enable HTTP basic authentication.
     ///
     /// Basic authentication will automatically be considered as a sensitive
-    /// header.
+    /// header. It replaces any authentication inherited from the [`Grillon`]
+    /// client.
+    ///
+    /// [`Grillon`]: crate::Grillon
     pub fn basic_auth<U, P>(mut self, username: U, password: Option<P>) -> Self
     where
         U: AsRef<str> + Display,
@@ -171,6 +583,7 @@ impl Request<'_> {
             username: username.to_string(),
             password: password.map(|pwd| pwd.to_string()),
         });
+        self.bearer_auth = None;
 
         self
     }
@@ -178,12 +591,44 @@ impl Request<'_> {
     /// Enable HTTP bearer authentication.
     ///
     /// Bearer authentication will automatically be considered as a sensitive
-    /// header.
+    /// header. It replaces any authentication inherited from the [`Grillon`]
+    /// client.
+    ///
+    /// [`Grillon`]: crate::Grillon
     pub fn bearer_auth<T>(mut self, token: T) -> Self
     where
         T: AsRef<str> + Display,
     {
         self.bearer_auth = Some(BearerToken(token.to_string()));
+        self.basic_auth = None;
+
+        self
+    }
+
+    /// Sets the total timeout of the [`Request`], from the moment the request
+    /// is sent until the response body has been received. It overrides the
+    /// timeout of the [`Grillon`] client.
+    ///
+    /// When the timeout is reached, the request is reported as an
+    /// unprocessable assertion with [`UnprocessableReason::Timeout`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use grillon::{Grillon, Result};
+    /// # use std::time::Duration;
+    /// # fn run() -> Result<()> {
+    /// Grillon::new("https://jsonplaceholder.typicode.com")?
+    ///     .get("users")
+    ///     .timeout(Duration::from_secs(2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Grillon`]: crate::Grillon
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
         self
     }
 
@@ -204,31 +649,58 @@ impl Request<'_> {
     /// # }
     /// ```
     pub async fn assert(self) -> Assert {
+        let request = RequestInfo {
+            method: self.method.to_string(),
+            url: self.url.to_string(),
+        };
+
         let headers = match self.headers {
             Ok(headers) => headers,
             Err(err) => {
-                let assertion = Assertion {
-                    part: Part::Headers,
-                    predicate: Predicate::NoPredicate,
-                    left: Hand::Empty::<Value>,
-                    right: Hand::Empty,
-                    result: AssertionResult::Unprocessable(
-                        UnprocessableReason::InvalidHttpRequestHeaders(err.to_string()),
-                    ),
-                };
-
-                assertion.assert(self.log_settings);
-
-                return Assert::new(None::<reqwest::Response>, None, self.log_settings.clone())
-                    .await;
+                return unprocessable(
+                    Part::Headers,
+                    UnprocessableReason::InvalidHttpRequestHeaders(err.to_string()),
+                    self.log_settings,
+                    request,
+                );
             }
         };
 
+        if let Some(err) = self.query_error {
+            return unprocessable(
+                Part::NoPart,
+                UnprocessableReason::InvalidHttpRequestUrl(err.to_string()),
+                self.log_settings,
+                request,
+            );
+        }
+
+        if let Some(err) = self.payload_error {
+            return unprocessable(
+                Part::NoPart,
+                UnprocessableReason::InvalidHttpRequestBody(err.to_string()),
+                self.log_settings,
+                request,
+            );
+        }
+
+        // Request headers take precedence over the content type of the
+        // payload, which takes precedence over the default headers.
+        let mut merged_headers = self.default_headers;
+        if let Some(content_type) = self.content_type {
+            merged_headers.insert(CONTENT_TYPE, content_type);
+        }
+        merged_headers.extend(headers);
+
         let mut req = self
             .client
             .request(self.method, self.url)
             .body(self.payload.unwrap_or_default())
-            .headers(headers);
+            .headers(merged_headers);
+
+        if let Some(timeout) = self.timeout {
+            req = req.timeout(timeout);
+        }
 
         // Check for auth settings
         if let Some(basic_auth) = self.basic_auth {
@@ -241,24 +713,19 @@ impl Request<'_> {
             req = req.bearer_auth(token);
         }
 
+        // The response body is read under the same timeout as the request,
+        // a timeout while receiving it is reported like a timeout while
+        // waiting for the response.
         let now = Instant::now();
         let response = match req.send().await {
             Ok(response) => response,
             Err(err) => {
-                let assertion = Assertion {
-                    part: Part::NoPart,
-                    predicate: Predicate::NoPredicate,
-                    left: Hand::Empty::<Value>,
-                    right: Hand::Empty,
-                    result: AssertionResult::Unprocessable(
-                        UnprocessableReason::HttpRequestFailure(err.to_string()),
-                    ),
-                };
-
-                assertion.assert(self.log_settings);
-
-                return Assert::new(None::<reqwest::Response>, None, self.log_settings.clone())
-                    .await;
+                return unprocessable(
+                    Part::NoPart,
+                    failure_reason(&err, now),
+                    self.log_settings,
+                    request,
+                );
             }
         };
 
@@ -270,11 +737,64 @@ impl Request<'_> {
         // See https://github.com/serde-rs/serde/issues/1183
         let response_time_ms = now.elapsed().as_millis() as u64;
 
-        Assert::new(
-            Some(response),
+        let headers = response.headers().clone();
+        let status = response.status();
+        let body = match response.bytes().await {
+            Ok(body) => body,
+            Err(err) => {
+                return unprocessable(
+                    Part::NoPart,
+                    failure_reason(&err, now),
+                    self.log_settings,
+                    request,
+                );
+            }
+        };
+
+        Assert::from_parts(
+            headers,
+            status,
+            body,
             Some(response_time_ms),
             self.log_settings.clone(),
         )
-        .await
+        .with_context(self.context.clone())
+        .with_request(request)
     }
 }
+
+/// Returns the reason of a request that failed while waiting for the response
+/// or while receiving its body.
+fn failure_reason(err: &reqwest::Error, sent_at: Instant) -> UnprocessableReason {
+    if err.is_timeout() {
+        UnprocessableReason::Timeout {
+            elapsed: sent_at.elapsed(),
+        }
+    } else {
+        UnprocessableReason::HttpRequestFailure(err.to_string())
+    }
+}
+
+/// Reports an http request that cannot be sent, or for which no response was
+/// received, as an unprocessable assertion and creates an [`Assert`] without
+/// response holding this assertion.
+fn unprocessable(
+    part: Part,
+    reason: UnprocessableReason,
+    log_settings: &LogSettings,
+    request: RequestInfo,
+) -> Assert {
+    let assertion = Assertion {
+        part,
+        predicate: Predicate::NoPredicate,
+        left: Hand::Empty::<Value>,
+        right: Hand::Empty,
+        result: AssertionResult::Unprocessable(reason),
+    };
+    let assertion = assertion.assert_with_request(log_settings, Some(&request));
+
+    let mut assert = Assert::without_response(log_settings.clone()).with_request(request);
+    assert.record(&assertion);
+
+    assert
+}
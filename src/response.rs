@@ -1,25 +1,100 @@
 //! The `response` module provides everything to implement custom responses that can
 //! be asserted with [`Assert`].
 //!
-//! [`Grillon`] provides a default implementation of [`Response`] with [`Hyper`](https://github.com/hyperium/hyper).
+//! [`Grillon`] provides a default implementation of [`Response`] with [`Reqwest`](https://github.com/seanmonstar/reqwest).
 //!
 //! [`Assert`]: crate::Assert
 //! [`Grillon`]: crate::Grillon
+use bytes::Bytes;
 use futures::{future::LocalBoxFuture, FutureExt};
 use http::{HeaderMap, StatusCode};
 use reqwest::Response as ReqwestResponse;
 use serde_json::Value;
+use std::fmt::Display;
+
+/// The maximum number of characters of the body kept in a
+/// [`JsonDecodeError`].
+const SNIPPET_MAX_CHARS: usize = 100;
+
+/// Error returned when the body of a response cannot be decoded as json.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonDecodeError {
+    /// The decoding error reported by serde.
+    pub reason: String,
+    /// The beginning of the body that failed to be decoded, truncated to
+    /// 100 characters.
+    pub snippet: String,
+}
+
+impl JsonDecodeError {
+    /// Creates a new `JsonDecodeError` from the serde error and the raw body
+    /// that failed to be decoded.
+    pub fn new(err: &serde_json::Error, body: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(body);
+        let mut snippet: String = text.chars().take(SNIPPET_MAX_CHARS).collect();
+        if text.chars().nth(SNIPPET_MAX_CHARS).is_some() {
+            snippet.push_str("...");
+        }
+
+        Self {
+            reason: err.to_string(),
+            snippet,
+        }
+    }
+}
+
+impl Display for JsonDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} in body `{}`", self.reason, self.snippet)
+    }
+}
+
+impl std::error::Error for JsonDecodeError {}
 
 /// A generic http response representation with
 /// convenience methods for subsequent assertions
 /// with [`Assert`].
 ///
+/// Only [`Response::bytes`] needs to be implemented to access the body of the
+/// response, the json and text representations are derived from the raw
+/// bytes.
+///
 /// [`Assert`]: crate::Assert
 pub trait Response {
     /// Returns the http status code.
     fn status(&self) -> StatusCode;
-    /// Returns a future with the response json body.
-    fn json<'a>(self) -> LocalBoxFuture<'a, Option<Value>>;
+    /// Returns a future with the raw response body.
+    fn bytes<'a>(self) -> LocalBoxFuture<'a, Option<Bytes>>;
+    /// Returns a future with the decoded response json body.
+    ///
+    /// The result is `Ok(None)` when the body is empty and an error when the
+    /// body cannot be decoded as json.
+    fn json<'a>(self) -> LocalBoxFuture<'a, Result<Option<Value>, JsonDecodeError>>
+    where
+        Self: Sized + 'a,
+    {
+        async move {
+            match self.bytes().await {
+                Some(bytes) => decode_json(&bytes),
+                None => Ok(None),
+            }
+        }
+        .boxed_local()
+    }
+    /// Returns a future with the response text body.
+    ///
+    /// Invalid UTF-8 sequences are replaced with the replacement character.
+    fn text<'a>(self) -> LocalBoxFuture<'a, Option<String>>
+    where
+        Self: Sized + 'a,
+    {
+        async move {
+            let bytes = self.bytes().await?;
+
+            Some(decode_text(&bytes))
+        }
+        .boxed_local()
+    }
     /// Returns the response headers.
     fn headers(&self) -> HeaderMap;
 }
@@ -29,23 +104,63 @@ impl Response for ReqwestResponse {
         self.status()
     }
 
-    fn json<'a>(self) -> LocalBoxFuture<'a, Option<Value>> {
-        async move {
-            if let Ok(bytes) = self.bytes().await {
-                if bytes.is_empty() {
-                    return None;
-                }
-                let json: Value = serde_json::from_slice(&bytes).expect("Failed to decode json");
-
-                Some(json)
-            } else {
-                None
-            }
-        }
-        .boxed_local()
+    fn bytes<'a>(self) -> LocalBoxFuture<'a, Option<Bytes>> {
+        async move { self.bytes().await.ok() }.boxed_local()
     }
 
     fn headers(&self) -> HeaderMap {
         self.headers().clone()
     }
 }
+
+/// Decodes the raw body as json. An empty body results in `Ok(None)`.
+pub(crate) fn decode_json(bytes: &[u8]) -> Result<Option<Value>, JsonDecodeError> {
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_slice(bytes)
+        .map(Some)
+        .map_err(|err| JsonDecodeError::new(&err, bytes))
+}
+
+/// Decodes the raw body as an UTF-8 string.
+pub(crate) fn decode_text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_json, JsonDecodeError};
+    use serde_json::json;
+
+    #[test]
+    fn it_should_decode_empty_body() {
+        assert_eq!(decode_json(b""), Ok(None));
+    }
+
+    #[test]
+    fn it_should_decode_json_body() {
+        assert_eq!(decode_json(br#"{"id": 1}"#), Ok(Some(json!({"id": 1}))));
+    }
+
+    #[test]
+    fn it_should_fail_to_decode_html_body() {
+        let err = decode_json(b"<html></html>").unwrap_err();
+
+        assert_eq!(err.snippet, "<html></html>");
+        assert_eq!(
+            err.to_string(),
+            "expected value at line 1 column 1 in body `<html></html>`"
+        );
+    }
+
+    #[test]
+    fn it_should_truncate_the_body_snippet() {
+        let body = "a".repeat(150);
+        let err = serde_json::from_str::<serde_json::Value>(&body).unwrap_err();
+        let err = JsonDecodeError::new(&err, body.as_bytes());
+
+        assert_eq!(err.snippet, format!("{}...", "a".repeat(100)));
+    }
+}
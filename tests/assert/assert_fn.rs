@@ -25,7 +25,7 @@ async fn custom_assert() -> Result<()> {
             assert!(status == Some(StatusCode::OK));
 
             if let Some(json) = json {
-                assert!(json.is_some());
+                assert!(matches!(json, Ok(Some(_))));
                 println!("Json response : {:#?}", json);
             }
         })
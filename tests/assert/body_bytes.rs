@@ -0,0 +1,58 @@
+use crate::HttpMockServer;
+use bytes::Bytes;
+use grillon::{
+    dsl::{contains, does_not_contain, is, is_not, matches},
+    Grillon, Result,
+};
+
+#[tokio::test]
+async fn body_bytes_equality() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_binary_file();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("logo.png")
+        .assert()
+        .await
+        .body_bytes(is(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR"))
+        .body_bytes(is(Bytes::from_static(
+            b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR",
+        )))
+        .body_bytes(is_not(Vec::new()));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn body_bytes_contains() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_binary_file();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("logo.png")
+        .assert()
+        .await
+        .body_bytes(contains(&b"IHDR"[..]))
+        .body_bytes(does_not_contain(b"IEND"))
+        .body_bytes(matches(r"(?-u)^\x89PNG"));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic]
+async fn body_bytes_equality_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_binary_file();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("logo.png")
+        .assert()
+        .await
+        .body_bytes(is(b"GIF89a"));
+}
@@ -0,0 +1,173 @@
+use crate::HttpMockServer;
+use grillon::{
+    dsl::{http::is_success, is, is_not},
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    json, Grillon, Result,
+};
+
+#[tokio::test]
+async fn captured_values_should_be_interpolated() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let post_mock = mock_server.post_valid_user();
+    let get_mock = mock_server.get_valid_user();
+    let put_mock = mock_server.put_valid_user();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    grillon
+        .post("users")
+        .payload(json!({ "name": "Isaac" }))
+        .headers(vec![(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )])
+        .assert()
+        .await
+        .status(is_success())
+        .capture("user_id", "$.id")
+        .capture("user_name", "$.name");
+
+    assert_eq!(grillon.context().get("user_id"), Some(json!(1)));
+
+    grillon
+        .get("users/{{user_id}}")
+        .assert()
+        .await
+        .status(is_success())
+        .json_path("$.name", is(json!("Isaac")));
+
+    grillon
+        .put("users/{{user_id}}")
+        .payload(json!({ "name": "{{user_name}}" }))
+        .headers(vec![(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )])
+        .assert()
+        .await
+        .status(is(204));
+
+    post_mock.assert();
+    get_mock.assert();
+    put_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn captured_header_should_be_interpolated() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let session_mock = mock_server.get_valid_user();
+    let auth_mock = mock_server.bearer_auth();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    grillon
+        .get("users/1")
+        .assert()
+        .await
+        .capture_header("date", "date");
+
+    assert_eq!(grillon.context().get("date"), Some(json!("today")));
+
+    grillon.context().set("token", json!("token-123"));
+    grillon
+        .get("auth/bearer/endpoint")
+        .headers(vec![(
+            AUTHORIZATION,
+            HeaderValue::from_static("Bearer {{token}}"),
+        )])
+        .assert()
+        .await
+        .status(is_success());
+
+    session_mock.assert();
+    auth_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Capture failure: no value found at json path '$.email'")]
+async fn capture_missing_value() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_valid_user();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users/1")
+        .assert()
+        .await
+        .capture("email", "$.email");
+}
+
+#[tokio::test]
+#[should_panic(expected = "Unprocessable header: header key is missing")]
+async fn capture_missing_header() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_valid_user();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users/1")
+        .assert()
+        .await
+        .capture_header("location", "location");
+}
+
+#[tokio::test]
+async fn capture_all_should_store_an_array() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_users_page();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    grillon
+        .get("users")
+        .assert()
+        .await
+        .capture_all("ids", "$[*].id")
+        .capture_all("first_ids", "$[0].id")
+        .capture_all("emails", "$[*].email");
+
+    assert_eq!(grillon.context().get("ids"), Some(json!([1, 2, 3])));
+    assert_eq!(grillon.context().get("first_ids"), Some(json!([1])));
+    assert_eq!(grillon.context().get("emails"), Some(json!([])));
+
+    mock.assert_hits(1);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(
+    expected = "Capture failure: 3 values found at json path '$[*].id', use capture_all to capture them"
+)]
+async fn capture_multiple_values() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_users_page();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users")
+        .assert()
+        .await
+        .capture("id", "$[*].id");
+}
+
+#[tokio::test]
+async fn captured_values_should_not_be_interpolated_in_expected_values() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_order();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+    grillon.context().set("uuid", json!("ord_1042"));
+
+    grillon
+        .get("orders/{{uuid}}")
+        .assert()
+        .await
+        .status(is_success())
+        .json_path("$.id", is(json!("{{uuid}}")))
+        .json_path("$.ref", is_not(json!("{{uuid}}")));
+
+    mock.assert();
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use crate::HttpMockServer;
 use grillon::dsl::{
-    contains,
-    http::{is_client_error, is_success},
+    contains, does_not_exist, exists,
+    http::{cookie_attributes, is_client_error, is_success, SameSite},
+    includes, is, is_absent, is_not,
 };
 use grillon::{
     header::{HeaderValue, SET_COOKIE},
@@ -20,10 +21,8 @@ async fn cookies_should_be_stored() -> Result<()> {
         .post("auth/session")
         .assert()
         .await
-        .headers(contains(vec![(
-            SET_COOKIE,
-            HeaderValue::from_static("SESSIONID=123; HttpOnly"),
-        )]));
+        .cookie("SESSIONID", is("123"))
+        .cookie("SESSIONID", includes(cookie_attributes().http_only(true)));
 
     grillon
         .get("auth/session/endpoint")
@@ -96,3 +95,206 @@ async fn missing_cookies_should_panic() {
     // This should panic because the SESSIONID cookie isn't set.
     auth_endpoint_mock.assert();
 }
+
+#[tokio::test]
+async fn cookie_attributes_in_any_order() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_login();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .post("login")
+        .assert()
+        .await
+        .cookie("session", is("abc123"))
+        .cookie("session", is_not("def456".to_string()))
+        .cookie(
+            "session",
+            includes(
+                cookie_attributes()
+                    .http_only(true)
+                    .secure(true)
+                    .same_site(SameSite::Lax)
+                    .path("/")
+                    .domain("example.com")
+                    .max_age(3600)
+                    .expires("Wed, 21 Oct 2026 07:28:00 GMT"),
+            ),
+        )
+        .cookie("session", exists())
+        .cookie("tracking", does_not_exist())
+        .cookie("tracking", is_absent());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn last_set_cookie_should_be_asserted() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_login();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .post("login")
+        .assert()
+        .await
+        .cookie("theme", is("dark"))
+        .cookie(
+            "theme",
+            includes(
+                cookie_attributes()
+                    .same_site(SameSite::Strict)
+                    .http_only(false),
+            ),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "should include: Cookie {")]
+async fn cookie_attribute_mismatch_should_panic() {
+    let mock_server = HttpMockServer::new();
+    mock_server.post_login();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .post("login")
+        .assert()
+        .await
+        .cookie(
+            "session",
+            includes(cookie_attributes().same_site(SameSite::Strict)),
+        );
+}
+
+#[tokio::test]
+#[should_panic(expected = "Unprocessable cookie: no Set-Cookie header sets tracking")]
+async fn missing_cookie_should_be_unprocessable() {
+    let mock_server = HttpMockServer::new();
+    mock_server.post_login();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .post("login")
+        .assert()
+        .await
+        .cookie("tracking", is("1"));
+}
+
+#[tokio::test]
+async fn cookie_jar_should_list_stored_cookies() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let auth_mock = mock_server.session_auth();
+    let url = mock_server.server.url("/");
+
+    let grillon = Grillon::new(&url)?.store_cookies(true)?;
+    assert!(grillon.cookie_jar().cookies(&url)?.is_empty());
+
+    grillon
+        .post("auth/session")
+        .assert()
+        .await
+        .status(is_success());
+
+    // Without a Path attribute, the cookie is only sent under the path of the
+    // request.
+    assert!(grillon.cookie_jar().cookies(&url)?.is_empty());
+    let cookies = grillon
+        .cookie_jar()
+        .cookies(&mock_server.server.url("/auth/session"))?;
+    assert_eq!(cookies.len(), 1);
+    assert_eq!(cookies[0].name.as_deref(), Some("SESSIONID"));
+    assert_eq!(cookies[0].value.as_deref(), Some("123"));
+    assert_eq!(cookies[0].http_only, Some(true));
+    assert_eq!(cookies[0].path.as_deref(), Some("/auth"));
+
+    auth_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cookie_jar_should_send_added_cookies() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let auth_endpoint_mock = mock_server.session_based_request();
+    let url = mock_server.server.url("/");
+
+    let grillon = Grillon::new(&url)?.store_cookies(true)?;
+    grillon.cookie_jar().add("SESSIONID=123; Path=/", &url)?;
+
+    grillon
+        .get("auth/session/endpoint")
+        .assert()
+        .await
+        .status(is_success());
+
+    auth_endpoint_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cookie_jar_should_not_send_removed_cookies() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let auth_endpoint_mock = mock_server.session_based_request();
+    let url = mock_server.server.url("/");
+
+    let grillon = Grillon::new(&url)?.store_cookies(true)?;
+    grillon.cookie_jar().add("SESSIONID=123; Path=/", &url)?;
+    assert!(grillon.cookie_jar().remove("SESSIONID", &url)?);
+
+    grillon
+        .get("auth/session/endpoint")
+        .assert()
+        .await
+        .status(is_client_error());
+
+    auth_endpoint_mock.assert_hits(0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cookie_jar_should_be_saved_and_loaded() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let auth_mock = mock_server.session_auth();
+    let auth_endpoint_mock = mock_server.session_based_request();
+    let url = mock_server.server.url("/");
+    let path = std::env::temp_dir().join(format!("grillon-cookies-{}.json", std::process::id()));
+
+    let session_url = mock_server.server.url("/auth/session");
+
+    let login = Grillon::new(&url)?.store_cookies(true)?;
+    login
+        .post("auth/session")
+        .assert()
+        .await
+        .status(is_success());
+    let cookies = login.cookie_jar().cookies(&session_url)?;
+    login.cookie_jar().save(&path)?;
+
+    let grillon = Grillon::new(&url)?.store_cookies(true)?;
+    assert!(grillon.cookie_jar().cookies(&session_url)?.is_empty());
+    grillon.cookie_jar().load(&path)?;
+    std::fs::remove_file(&path).expect("Removed cookie jar file");
+
+    let loaded = grillon.cookie_jar().cookies(&session_url)?;
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded, cookies);
+    assert_eq!(loaded[0].name.as_deref(), Some("SESSIONID"));
+    assert_eq!(loaded[0].value.as_deref(), Some("123"));
+
+    grillon
+        .get("auth/session/endpoint")
+        .assert()
+        .await
+        .status(is_success());
+
+    auth_mock.assert();
+    auth_endpoint_mock.assert();
+
+    Ok(())
+}
@@ -1,7 +1,13 @@
 use crate::HttpMockServer;
 use grillon::{
-    dsl::{contains, does_not_contain, is, is_not},
-    header::{HeaderMap, HeaderValue, CONTENT_LENGTH, CONTENT_TYPE, DATE},
+    dsl::{
+        contains, does_not_contain, does_not_match, ends_with, exists, is, is_absent, is_not,
+        matches, matches_snapshot, starts_with,
+    },
+    header::{
+        HeaderMap, HeaderValue, CONTENT_LENGTH, CONTENT_TYPE, DATE, ETAG, LOCATION, SERVER,
+        SET_COOKIE, VARY,
+    },
     Grillon, Method, Result,
 };
 
@@ -154,3 +160,213 @@ async fn single_header_equality() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn single_header_string_shape() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_order();
+
+    Grillon::new(mock_server.server.url("/").as_ref())?
+        .post("orders")
+        .assert()
+        .await
+        .header(ETAG, matches(r#"^W/"[0-9a-f]+-[0-9a-f]+"$"#))
+        .header(ETAG, does_not_match("^[0-9]+$".to_string()))
+        .header(LOCATION, starts_with("/orders/"))
+        .header(LOCATION, ends_with("ord_1042".to_string()))
+        .header(CONTENT_TYPE, contains("application/json"))
+        .header(CONTENT_TYPE, does_not_contain("text/html"));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "should start with: \"/users/\"")]
+async fn single_header_starts_with_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.post_order();
+
+    Grillon::new(mock_server.server.url("/").as_ref())
+        .unwrap()
+        .post("orders")
+        .assert()
+        .await
+        .header(LOCATION, starts_with("/users/"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "Invalid regex pattern: ^W/(")]
+async fn single_header_invalid_regex() {
+    let mock_server = HttpMockServer::new();
+    mock_server.post_order();
+
+    Grillon::new(mock_server.server.url("/").as_ref())
+        .unwrap()
+        .post("orders")
+        .assert()
+        .await
+        .header(ETAG, matches("^W/("));
+}
+
+#[tokio::test]
+async fn single_header_presence() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_order();
+
+    Grillon::new(mock_server.server.url("/").as_ref())?
+        .post("orders")
+        .assert()
+        .await
+        .header(LOCATION, exists())
+        .header("x-powered-by", is_absent())
+        .header(SERVER, is_absent());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "should not exist")]
+async fn single_header_absence_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.post_order();
+
+    Grillon::new(mock_server.server.url("/").as_ref())
+        .unwrap()
+        .post("orders")
+        .assert()
+        .await
+        .header(ETAG, is_absent());
+}
+
+#[tokio::test]
+#[should_panic(expected = "header key is missing")]
+async fn single_header_missing() {
+    let mock_server = HttpMockServer::new();
+    mock_server.post_order();
+
+    Grillon::new(mock_server.server.url("/").as_ref())
+        .unwrap()
+        .post("orders")
+        .assert()
+        .await
+        .header(SERVER, is("nginx"));
+}
+
+#[tokio::test]
+async fn header_values() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_preferences();
+
+    Grillon::new(mock_server.server.url("/").as_ref())?
+        .get("preferences")
+        .assert()
+        .await
+        .header_values(VARY, is(vec!["Origin", "Accept-Encoding"]))
+        .header_values(VARY, is_not(vec!["Accept-Encoding", "Origin"]))
+        .header_values(
+            SET_COOKIE,
+            is(vec![
+                "theme=dark; Path=/".to_string(),
+                "lang=en; Path=/".to_string(),
+            ]),
+        )
+        .header_values(SET_COOKIE, contains("lang=en; Path=/"))
+        .header_values(
+            SET_COOKIE,
+            does_not_contain("theme=light; Path=/".to_string()),
+        )
+        .header_values("x-powered-by", is(Vec::<&str>::new()));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "should contain: [")]
+async fn header_values_contains_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_preferences();
+
+    Grillon::new(mock_server.server.url("/").as_ref())
+        .unwrap()
+        .get("preferences")
+        .assert()
+        .await
+        .header_values(VARY, contains("Cookie"));
+}
+
+#[tokio::test]
+async fn headers_matches_snapshot() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_preferences();
+
+    Grillon::new(mock_server.server.url("/").as_ref())?
+        .get("preferences")
+        .assert()
+        .await
+        .headers_snapshot(matches_snapshot("preferences_headers").redacting(["$.date"]));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn headers_matches_missing_snapshot() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_preferences();
+    let dir = std::env::temp_dir().join(format!("grillon-snapshots-{}", std::process::id()));
+    let path = dir.join("missing_headers.json");
+    let _ = std::fs::remove_file(&path);
+
+    Grillon::new(mock_server.server.url("/").as_ref())?
+        .get("preferences")
+        .assert()
+        .await
+        .headers_snapshot(
+            matches_snapshot("missing_headers")
+                .dir(&dir)
+                .redacting(["$.date"]),
+        );
+
+    let written = std::fs::read_to_string(&path).expect("Written snapshot file");
+    std::fs::remove_dir_all(&dir).expect("Removed snapshot dir");
+    assert_eq!(
+        written,
+        std::fs::read_to_string("tests/snapshots/preferences_headers.json")
+            .expect("Preferences headers snapshot")
+    );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "diff:\n+ /vary/1: \"Accept-Encoding\"")]
+async fn headers_matches_snapshot_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_preferences();
+    let dir = std::env::temp_dir().join(format!("grillon-changed-{}", std::process::id()));
+    let snapshot = std::fs::read_to_string("tests/snapshots/preferences_headers.json")
+        .expect("Preferences headers snapshot")
+        .replace(",\n    \"Accept-Encoding\"", "");
+    std::fs::create_dir_all(&dir).expect("Created snapshot dir");
+    std::fs::write(dir.join("changed_headers.json"), snapshot).expect("Written snapshot file");
+
+    Grillon::new(mock_server.server.url("/").as_ref())
+        .unwrap()
+        .get("preferences")
+        .assert()
+        .await
+        .headers_snapshot(
+            matches_snapshot("changed_headers")
+                .dir(&dir)
+                .redacting(["$.date"]),
+        );
+}
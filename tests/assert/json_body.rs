@@ -1,9 +1,13 @@
 use crate::HttpMockServer;
 use grillon::{
-    dsl::{is, is_not},
+    dsl::{
+        contains, has_length, includes, is, is_between, is_not, is_type, is_unordered,
+        matches_snapshot, ArrayMatching, JsonType,
+    },
     header::{HeaderValue, CONTENT_TYPE},
-    json, Grillon, Result,
+    json, Grillon, LogSettings, Result,
 };
+use std::path::PathBuf;
 
 #[tokio::test]
 async fn json_body() -> Result<()> {
@@ -123,3 +127,521 @@ async fn it_should_fail_to_compare_inexistant_body() {
             "name": "Isaac",
         })));
 }
+
+#[tokio::test]
+#[should_panic(expected = "Invalid json body: expected value at line 1 column 1")]
+async fn it_should_fail_to_compare_invalid_json_body() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_invalid_json();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users/invalid")
+        .assert()
+        .await
+        .json_body(is(json!({
+            "id": 1,
+            "name": "Isaac",
+        })));
+}
+
+#[tokio::test]
+async fn invalid_json_body_should_not_panic_with_json_output() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_invalid_json();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::JsonOutput)
+        .get("users/invalid")
+        .assert()
+        .await
+        .json_body(is(json!({ "id": 1 })))
+        .json_path("$.id", is(json!(1)))
+        .text_body(contains("Bad gateway"));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_body_has_length() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_users_page();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("users")
+        .assert()
+        .await
+        .json_body(has_length(3))
+        .json_body(has_length(is_between(1, 20)));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "should have length: Number(20)\nwas: Number(3)")]
+async fn json_body_has_length_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_users_page();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users")
+        .assert()
+        .await
+        .json_body(has_length(20));
+}
+
+#[tokio::test]
+async fn json_body_is_type() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_users_page();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("users")
+        .assert()
+        .await
+        .json_body(is_type(JsonType::Array));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_body_includes() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_body(includes(json!({
+            "currency": "EUR",
+            "items": [{ "name": "notebook" }, { "name": "pen" }]
+        })))
+        .json_body(
+            includes(json!({ "items": [{ "name": "notebook" }] })).arrays(ArrayMatching::Prefix),
+        )
+        .json_body(
+            includes(json!({ "items": [{ "quantity": 10 }] })).arrays(ArrayMatching::AnyOrder),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "missing: \"/discount\"\ndifferent: \"/currency\", \"/items\"")]
+async fn json_body_includes_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_body(includes(json!({
+            "currency": "USD",
+            "discount": 10,
+            "items": [{ "name": "notebook" }]
+        })));
+}
+
+#[tokio::test]
+#[should_panic(expected = "~ /name: \"Max\" -> \"Isaac\"")]
+async fn json_body_failure_should_log_the_diff() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_valid_user();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users/1")
+        .assert()
+        .await
+        .json_body(is(json!({ "id": 1, "name": "Max" })));
+}
+
+#[tokio::test]
+async fn json_body_ignoring() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_body(
+            is(json!({
+                "items": [{ "name": "notebook", "quantity": 2 }, { "name": "pen", "quantity": 10 }],
+                "currency": "EUR",
+                "coupon": null
+            }))
+            .ignoring(["$.total", "$..price"]),
+        )
+        .json_body(is(PathBuf::from("tests/fixtures/cart.json")).ignoring(["$.total", "$..price"]))
+        .json_body(
+            is_not(json!({ "currency": "USD", "total": 1 })).ignoring(["$.items", "$.coupon"]),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "~ /currency: \"USD\" -> \"EUR\"")]
+async fn json_body_ignoring_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_body(is(json!({ "currency": "USD" })).ignoring(["$.items", "$.total", "$.coupon"]));
+}
+
+#[tokio::test]
+async fn json_body_placeholders() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_order();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("orders/ord_1042")
+        .assert()
+        .await
+        .json_body(is(json!({
+            "id": "{{uuid}}",
+            "ref": "{{regex:^ord_[0-9]+$}}",
+            "customer": "Isaac",
+            "total": "{{number}}",
+            "createdAt": "{{iso8601}}",
+            "etag": "{{any_string}}"
+        })))
+        .json_body(is(PathBuf::from("tests/fixtures/order_shape.json")))
+        .json_body(is_not(json!({
+            "id": "{{uuid}}",
+            "ref": "{{regex:^ord_[0-9]+$}}",
+            "customer": "{{number}}",
+            "total": "{{number}}",
+            "createdAt": "{{iso8601}}",
+            "etag": "{{any_string}}"
+        })));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_path_placeholders() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_order();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("orders/ord_1042")
+        .assert()
+        .await
+        .json_path("$.id", is("\"{{uuid}}\""))
+        .json_path("$.createdAt", is(json!("{{iso8601}}")))
+        .json_path("$.customer", is_not(json!("{{number}}")))
+        .json_path(
+            "$",
+            is(json!({ "ref": "{{regex:^ord_}}", "total": "{{number}}" })).ignoring([
+                "$.id",
+                "$.customer",
+                "$.createdAt",
+                "$.etag",
+            ]),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "~ /createdAt: \"{{uuid}}\" -> \"2024-05-12T10:42:00Z\"")]
+async fn json_body_placeholders_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_order();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("orders/ord_1042")
+        .assert()
+        .await
+        .json_body(
+            is(json!({ "id": "{{uuid}}", "createdAt": "{{uuid}}" })).ignoring([
+                "$.ref",
+                "$.customer",
+                "$.total",
+                "$.etag",
+            ]),
+        );
+}
+
+#[tokio::test]
+async fn json_body_unordered_placeholders() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_users_page();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("users")
+        .assert()
+        .await
+        .json_body(
+            is(json!([
+                { "id": "{{number}}", "name": "Isaac" },
+                { "id": 3, "name": "Olivia" },
+                { "id": 2, "name": "Max" }
+            ]))
+            .unordered(),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "- /0: {\"id\":3,\"name\":\"Isaac\"}")]
+async fn json_body_unordered_placeholders_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_users_page();
+
+    // The placeholder is resolved against the first actual item.
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users")
+        .assert()
+        .await
+        .json_body(
+            is(json!([
+                { "id": 3, "name": "{{any_string}}" },
+                { "id": 1, "name": "Isaac" },
+                { "id": 2, "name": "Max" }
+            ]))
+            .unordered(),
+        );
+}
+
+#[tokio::test]
+async fn json_body_matches_snapshot() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_order();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("orders/ord_1042")
+        .assert()
+        .await
+        .json_body(matches_snapshot("order").redacting(["$.id", "$.createdAt", "$.etag"]));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "~ /id: \"[redacted]\" -> \"9f1c2d3e-4b5a-4c6d-8e7f-0a1b2c3d4e5f\"")]
+async fn json_body_matches_snapshot_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_order();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("orders/ord_1042")
+        .assert()
+        .await
+        .json_body(matches_snapshot("order").redacting(["$.createdAt", "$.etag"]));
+}
+
+#[tokio::test]
+async fn json_body_tolerance() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_body(
+            is(json!({
+                "items": [
+                    { "name": "notebook", "price": 9.989, "quantity": 2 },
+                    { "name": "pen", "price": 1.501, "quantity": 10.0 }
+                ],
+                "total": 9.99 * 2.0 + 1.5 * 10.0,
+                "currency": "EUR",
+                "coupon": null
+            }))
+            .tolerance(0.005),
+        )
+        .json_body(
+            is_not(json!({ "total": 35 }))
+                .ignoring(["$.items", "$.currency", "$.coupon"])
+                .tolerance(0.005),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "~ /total: 35 -> 34.98")]
+async fn json_body_tolerance_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_body(
+            is(json!({ "total": 35, "currency": "EUR" }))
+                .ignoring(["$.items", "$.coupon"])
+                .tolerance(0.01),
+        );
+}
+
+#[tokio::test]
+async fn json_body_tolerance_unordered() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_body(
+            is(json!({ "items": [{ "price": 1.49 }, { "price": 10.0 }], "total": 35 }))
+                .ignoring(["$..name", "$..quantity", "$.currency", "$.coupon"])
+                .tolerance(0.05)
+                .unordered(),
+        )
+        .json_body(
+            is_not(json!({ "items": [{ "price": 1.4 }, { "price": 10.0 }], "total": 35 }))
+                .ignoring(["$..name", "$..quantity", "$.currency", "$.coupon"])
+                .tolerance(0.05)
+                .unordered(),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "diff:\n- /items/0: {\"price\":1.4}\n+ /items/1: {\"price\":1.5}")]
+async fn json_body_tolerance_unordered_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_body(
+            is(json!({ "items": [{ "price": 1.4 }, { "price": 10.0 }], "total": 35 }))
+                .ignoring(["$..name", "$..quantity", "$.currency", "$.coupon"])
+                .tolerance(0.05)
+                .unordered(),
+        );
+}
+
+#[tokio::test]
+async fn json_body_unordered() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_users_page();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("users")
+        .assert()
+        .await
+        .json_body(
+            is(json!([
+                { "id": 3, "name": "Olivia" },
+                { "id": 1, "name": "Isaac" },
+                { "id": 2, "name": "Max" }
+            ]))
+            .unordered(),
+        )
+        .json_body(
+            is_not(json!([
+                { "id": 3, "name": "Olivia" },
+                { "id": 1, "name": "Isaac" }
+            ]))
+            .unordered(),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_body_is_unordered_from_str_and_file() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_users_page();
+    let users = r#"[{ "id": 2, "name": "Max" }, { "id": 3, "name": "Olivia" }, { "id": 1, "name": "Isaac" }]"#;
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("users")
+        .assert()
+        .await
+        .json_body(is_unordered(users))
+        .json_body(is_unordered(users.to_string()))
+        .json_body(is_unordered(PathBuf::from(
+            "tests/fixtures/users_unordered.json",
+        )));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Failed to read json file located at tests/fixtures/no_users.json")]
+async fn json_body_is_unordered_missing_file() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_users_page();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users")
+        .assert()
+        .await
+        .json_body(is_unordered(PathBuf::from("tests/fixtures/no_users.json")));
+}
+
+#[tokio::test]
+#[should_panic(expected = "- /2: {\"id\":4,\"name\":\"Zoe\"}")]
+async fn json_body_unordered_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_users_page();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users")
+        .assert()
+        .await
+        .json_body(
+            is(json!([
+                { "id": 3, "name": "Olivia" },
+                { "id": 1, "name": "Isaac" },
+                { "id": 4, "name": "Zoe" }
+            ]))
+            .unordered(),
+        );
+}
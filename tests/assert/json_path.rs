@@ -1,6 +1,10 @@
 use crate::HttpMockServer;
 use grillon::{
-    dsl::{contains, does_not_contain, does_not_match, is, is_not, matches},
+    dsl::{
+        approx, contains, does_not_contain, does_not_exist, does_not_match, exists, has_length, is,
+        is_between, is_greater_or_equal, is_greater_than, is_less_or_equal, is_less_than, is_not,
+        is_type, is_unordered, matches, JsonType,
+    },
     json, Grillon, Result,
 };
 
@@ -194,3 +198,303 @@ async fn json_path_regex_fails_with_null_value() {
 
     mock.assert_hits(0);
 }
+
+#[tokio::test]
+#[should_panic(expected = "Invalid json body")]
+async fn json_path_with_invalid_json_body() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_invalid_json();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users/invalid")
+        .assert()
+        .await
+        .json_path("$.id", is(json!(1)));
+}
+
+#[tokio::test]
+async fn json_path_compares_numbers() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.total", is_greater_than(30))
+        .json_path("$.total", is_less_than(json!(35)))
+        .json_path("$.total", is_between(34.98, 40.0))
+        .json_path("$.items[*].quantity", is_greater_or_equal(2))
+        .json_path("$.items[*].price", is_less_or_equal(9.99))
+        .json_path("$.items[*].price", is_between(1, 10));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "should be greater than")]
+async fn json_path_greater_than_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.items[*].quantity", is_greater_than(2));
+}
+
+#[tokio::test]
+#[should_panic(expected = "Expected a number")]
+async fn json_path_compares_non_number() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.currency", is_less_than(10));
+}
+
+#[tokio::test]
+async fn json_path_exists() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.items[0].name", exists())
+        .json_path("$.coupon", exists())
+        .json_path("$.discount", does_not_exist())
+        .json_path("$.items[*].password", does_not_exist())
+        .json_path("$.items[5]", does_not_exist());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "should not exist")]
+async fn json_path_does_not_exist_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.coupon", does_not_exist());
+}
+
+#[tokio::test]
+async fn json_path_has_length() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.items", has_length(2))
+        .json_path("$.items[0]", has_length(3))
+        .json_path("$.currency", has_length(is_between(3, 3)));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Unprocessable length")]
+async fn json_path_has_length_on_several_nodes() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.items[*]", has_length(2));
+}
+
+#[tokio::test]
+async fn json_path_is_type() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.items", is_type(JsonType::Array))
+        .json_path("$.items[*].name", is_type(JsonType::String))
+        .json_path("$.items[*].quantity", is_type(JsonType::Integer))
+        .json_path("$.total", is_type(JsonType::Number))
+        .json_path("$.coupon", is_type(JsonType::Null));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "was: Array [\n    String(\"number\"),")]
+async fn json_path_is_type_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.total", is_type(JsonType::Integer));
+}
+
+#[tokio::test]
+async fn json_path_ignoring() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_path(
+            "$.items[0]",
+            is(json!({ "name": "notebook", "quantity": 2 })).ignoring(["$.price"]),
+        )
+        .json_path(
+            "$.items",
+            is(json!([{ "name": "notebook" }, { "name": "pen" }]))
+                .ignoring(["$..price", "$..quantity"]),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_path_approx() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.total", approx(9.99 * 2.0 + 1.5 * 10.0, 1e-9))
+        .json_path("$.items[0].quantity", approx(2.001, 0.01))
+        .json_path(
+            "$.items[1]",
+            approx(
+                json!({ "name": "pen", "price": 1.49, "quantity": 10 }),
+                0.02,
+            ),
+        )
+        .json_path(
+            "$.items[0]",
+            is(json!({ "name": "notebook", "price": 10, "quantity": 2 })).tolerance(0.05),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "~ /price: 1.6 -> 1.5")]
+async fn json_path_approx_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_path(
+            "$.items[1]",
+            approx(json!({ "name": "pen", "price": 1.6, "quantity": 10 }), 0.05),
+        );
+}
+
+#[tokio::test]
+async fn json_path_is_unordered() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_path(
+            "$.items",
+            is_unordered(json!([
+                { "name": "pen", "price": 1.5, "quantity": 10 },
+                { "name": "notebook", "price": 9.99, "quantity": 2 }
+            ])),
+        )
+        .json_path(
+            "$.items",
+            is(json!([{ "name": "pen" }, { "name": "notebook" }]))
+                .ignoring(["$..price", "$..quantity"])
+                .unordered(),
+        );
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_path_is_unordered_from_str() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_cart();
+    let items = r#"[
+        { "name": "pen", "price": 1.5, "quantity": 10 },
+        { "name": "notebook", "price": 9.99, "quantity": 2 }
+    ]"#;
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("cart")
+        .assert()
+        .await
+        .json_path("$.items", is_unordered(items))
+        .json_path("$.items", is_unordered(items.to_string()));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "- /1: {\"name\":\"eraser\"}")]
+async fn json_path_is_unordered_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_cart();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("cart")
+        .assert()
+        .await
+        .json_path(
+            "$.items",
+            is(json!([{ "name": "pen" }, { "name": "eraser" }]))
+                .ignoring(["$..price", "$..quantity"])
+                .unordered(),
+        );
+}
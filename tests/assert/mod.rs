@@ -1,10 +1,15 @@
 mod assert_fn;
 mod auth;
+mod body_bytes;
+mod capture;
 mod cookies;
 mod headers;
 mod json_body;
 mod json_path;
 mod json_schema;
+mod report;
 mod response_time;
+mod soft;
 mod status;
 mod surf_impl;
+mod text_body;
@@ -0,0 +1,145 @@
+use crate::HttpMockServer;
+use grillon::{
+    dsl::{http::is_success, is, is_less_than},
+    header::CONTENT_TYPE,
+    json, Error, Grillon, LogSettings, Result,
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn report_should_group_assertions_by_result() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_valid_user();
+
+    let report = Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::StdOutput)
+        .get("users/1")
+        .assert()
+        .await
+        .status(is(500))
+        .json_path("$.id", is(json!(1)))
+        .response_time(is_less_than(10_000))
+        .header("x-missing", is("value"))
+        .report();
+
+    mock.assert();
+
+    assert_eq!(report.passed.len(), 2);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.unprocessable.len(), 1);
+    assert!(!report.is_success());
+
+    let report = json!(report);
+    assert_eq!(report["failed"][0]["part"], "status code");
+    assert_eq!(report["failed"][0]["left"], 200);
+    assert_eq!(report["failed"][0]["right"], 500);
+    assert_eq!(report["passed"][0]["left"], json!(["$.id", [1]]));
+    assert_eq!(
+        report["unprocessable"][0]["result"],
+        json!({ "unprocessable": "missing_header" })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn into_result_should_return_the_report() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_valid_user();
+
+    let report = Grillon::new(&mock_server.server.url("/"))?
+        .get("users/1")
+        .assert()
+        .await
+        .status(is_success())
+        .header(CONTENT_TYPE, is("application/json"))
+        .into_result()?;
+
+    mock.assert();
+
+    assert!(report.is_success());
+    assert_eq!(report.passed.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn into_result_should_return_all_failures() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_valid_user();
+
+    let result = Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::JsonOutput)
+        .get("users/1")
+        .assert()
+        .await
+        .status(is(404))
+        .json_path("$.name", is(json!("Isaac")))
+        .json_path("$.id", is(json!(2)))
+        .into_result();
+
+    mock.assert();
+
+    let Err(Error::AssertionsFailed(failures)) = result else {
+        panic!("Expected the assertions to fail");
+    };
+    assert_eq!(failures.len(), 2);
+
+    let message = Error::AssertionsFailed(failures).to_string();
+    assert!(message.starts_with("2 assertion(s) failed"), "{message}");
+    assert!(message.contains("part: status code"), "{message}");
+    assert!(message.contains("part: json path '$.id'"), "{message}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn report_should_contain_request_failures() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_slow_response();
+
+    let report = Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::StdOutput)
+        .get("slow")
+        .timeout(Duration::from_millis(50))
+        .assert()
+        .await
+        .status(is_success())
+        .report();
+
+    assert_eq!(report.unprocessable.len(), 1);
+    assert!(report.unprocessable[0]
+        .message
+        .starts_with("Http request timeout after"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn report_should_carry_the_json_diff() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_valid_user();
+
+    let report = Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::StdOutput)
+        .get("users/1")
+        .assert()
+        .await
+        .json_body(is(json!({ "id": 1, "name": "Max", "age": 22 })))
+        .json_path("$.id", is(json!(1)))
+        .report();
+
+    mock.assert();
+
+    assert!(report.passed[0].diff.is_none());
+    assert_eq!(
+        json!(report.failed[0].diff),
+        json!({
+            "added": [],
+            "removed": [{ "pointer": "/age", "old": 22 }],
+            "changed": [{ "pointer": "/name", "old": "Max", "new": "Isaac" }]
+        })
+    );
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use crate::HttpMockServer;
+use grillon::{
+    dsl::{http::is_success, is, is_less_than},
+    header::CONTENT_TYPE,
+    json, Error, Grillon, LogSettings, Result,
+};
+
+#[tokio::test]
+async fn soft_assertions_should_pass() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_valid_user();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::SoftAssert)
+        .get("users/1")
+        .assert()
+        .await
+        .status(is_success())
+        .json_path("$.id", is(json!(1)))
+        .finish();
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "3 soft assertion(s) failed:
+- status code
+- json path '$.name'
+- header: Unprocessable header: header key is missing")]
+async fn soft_assertions_should_fail_on_finish() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_valid_user();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .log_settings(LogSettings::SoftAssert)
+        .get("users/1")
+        .assert()
+        .await
+        .status(is(404))
+        .json_path("$.id", is(json!(1)))
+        .json_path("$.name", is(json!("Rayne")))
+        .header(CONTENT_TYPE, is("application/json"))
+        .header("x-missing", is("value"))
+        .finish();
+}
+
+#[tokio::test]
+#[should_panic(expected = "1 soft assertion(s) failed:
+- response time")]
+async fn soft_assertions_should_fail_on_drop() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_valid_user();
+
+    let _assert = Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .log_settings(LogSettings::SoftAssert)
+        .get("users/1")
+        .assert()
+        .await
+        .response_time(is_less_than(0))
+        .status(is_success());
+}
+
+#[tokio::test]
+async fn soft_assertions_should_not_panic_with_into_result() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_valid_user();
+
+    let result = Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::SoftAssert)
+        .get("users/1")
+        .assert()
+        .await
+        .status(is(404))
+        .into_result();
+
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cloned_soft_assertions_should_be_reported_once() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_valid_user();
+
+    let assert = Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::SoftAssert)
+        .get("users/1")
+        .assert()
+        .await
+        .status(is(404));
+
+    // Dropping the clone doesn't report the failure of the original.
+    let clone = assert.clone();
+    assert_eq!(clone.report().failed.len(), 1);
+    drop(clone);
+
+    match assert.into_result() {
+        Err(Error::AssertionsFailed(failures)) => assert_eq!(failures.len(), 1),
+        result => panic!("Expected one failure, got {result:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "1 soft assertion(s) failed:
+- json path '$.name'")]
+async fn cloned_soft_assertions_should_report_their_own_failures() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_valid_user();
+
+    let assert = Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .log_settings(LogSettings::SoftAssert)
+        .get("users/1")
+        .assert()
+        .await
+        .status(is(404));
+
+    let result = assert.clone().into_result();
+    assert!(result.is_err());
+
+    assert
+        .clone()
+        .json_path("$.name", is(json!("Rayne")))
+        .finish();
+}
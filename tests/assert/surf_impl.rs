@@ -7,8 +7,12 @@ use http::{HeaderName, HeaderValue};
 #[tokio::test]
 async fn custom_response_struct() -> Result<()> {
     use async_trait::async_trait;
-    use grillon::{dsl::is_between, header::HeaderMap, Assert, Response, StatusCode};
-    use serde_json::Value;
+    use bytes::Bytes;
+    use grillon::{
+        dsl::{is, is_between},
+        header::HeaderMap,
+        json, Assert, Response, StatusCode,
+    };
 
     struct ResponseWrapper {
         pub response: surf::Response,
@@ -21,12 +25,8 @@ async fn custom_response_struct() -> Result<()> {
             StatusCode::from_u16(status).expect("Invalid status code range")
         }
 
-        async fn json(mut self) -> Option<Value> {
-            if let Ok(bytes) = &self.response.body_bytes().await {
-                return serde_json::from_slice::<Value>(bytes).ok();
-            }
-
-            None
+        async fn bytes(mut self) -> Option<Bytes> {
+            self.response.body_bytes().await.ok().map(Bytes::from)
         }
 
         fn headers(&self) -> HeaderMap {
@@ -81,6 +81,7 @@ async fn custom_response_struct() -> Result<()> {
     )
     .await
     .status(is_between(200, 299))
+    .json_body(is(json!({ "id": 1, "name": "Isaac" })))
     .assert_fn(|assert| {
         assert!(assert.status == Some(StatusCode::OK), "Bad status code");
     });
@@ -0,0 +1,86 @@
+use crate::HttpMockServer;
+use grillon::{
+    dsl::{contains, does_not_contain, does_not_match, is, is_not, matches},
+    Grillon, Result,
+};
+
+#[tokio::test]
+async fn text_body_equality() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_html_page();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("index.html")
+        .assert()
+        .await
+        .text_body(is("<html><body><h1>Hello, Isaac</h1></body></html>"))
+        .text_body(is_not("<html></html>".to_string()));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn text_body_contains() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_html_page();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("index.html")
+        .assert()
+        .await
+        .text_body(contains("<h1>Hello, Isaac</h1>"))
+        .text_body(does_not_contain("Rayne"));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn text_body_matches() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_html_page();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("index.html")
+        .assert()
+        .await
+        .text_body(matches(r"<h1>Hello, \w+</h1>"))
+        .text_body(does_not_match("<h2>".to_string()));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic]
+async fn text_body_does_not_contain_failure() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_html_page();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("index.html")
+        .assert()
+        .await
+        .text_body(does_not_contain("Isaac"));
+}
+
+#[tokio::test]
+async fn text_body_of_json_response() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_valid_user();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("users/1")
+        .assert()
+        .await
+        .text_body(contains(r#""name":"Isaac""#));
+
+    mock.assert();
+
+    Ok(())
+}
@@ -0,0 +1,254 @@
+use crate::HttpMockServer;
+use grillon::{
+    dsl::{http::is_success, is},
+    header::{HeaderValue, CONTENT_TYPE},
+    json, Grillon, Multipart, Result,
+};
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(Serialize)]
+struct UserForm {
+    name: &'static str,
+    city: &'static str,
+}
+
+#[tokio::test]
+async fn post_form() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_form_user();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .post("users")
+        .form(UserForm {
+            name: "Isaac Rayne",
+            city: "Paris",
+        })
+        .assert()
+        .await
+        .status(is_success())
+        .json_path("$.id", is(json!(1)));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn form_content_type_should_not_override_headers() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_form_user();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .post("users")
+        .headers(vec![(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )])
+        .form([("name", "Isaac Rayne"), ("city", "Paris")])
+        .assert()
+        .await
+        .status(is(404));
+
+    assert_eq!(mock.hits(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn form_should_not_be_sent_without_body_support() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    let request = grillon.get("users").form([("name", "Isaac")]);
+
+    assert!(request.payload.is_none());
+    assert!(request.content_type.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Invalid HTTP request body: Invalid form body")]
+async fn invalid_form() {
+    let mock_server = HttpMockServer::new();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .post("users")
+        .form(json!({ "user": { "name": "Isaac" } }))
+        .assert()
+        .await;
+}
+
+#[tokio::test]
+async fn invalid_form_should_be_replaced_by_a_valid_body() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_xml_user();
+
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    let request = grillon
+        .post("users")
+        .form(json!({ "user": { "name": "Isaac" } }));
+    assert!(request.payload.is_none());
+
+    request
+        .body_bytes("<user><name>Isaac</name></user>", "application/xml")
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn post_raw_bytes() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_xml_user();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .post("users")
+        .body_bytes("<user><name>Isaac</name></user>", "application/xml")
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Invalid HTTP request body: Invalid header value")]
+async fn raw_bytes_invalid_content_type() {
+    let mock_server = HttpMockServer::new();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .post("users")
+        .body_bytes(vec![0x0a, 0x05], "application/\nprotobuf")
+        .assert()
+        .await;
+}
+
+#[tokio::test]
+async fn post_text() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_text_comment();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .post("comments")
+        .body_text("Nice post!")
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_file() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.put_order_file();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .put("orders/4")
+        .body_file(PathBuf::from("tests/fixtures/order4.json"))
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Invalid HTTP request body: Failed to read file located at")]
+async fn unreadable_file() {
+    let mock_server = HttpMockServer::new();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .put("orders/4")
+        .body_file(PathBuf::from("tests/fixtures/does_not_exist.json"))
+        .assert()
+        .await;
+}
+
+#[tokio::test]
+async fn post_multipart() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_multipart_avatar();
+
+    let form = Multipart::new().text("name", "Isaac").bytes(
+        "avatar",
+        "avatar.png",
+        b"\x89PNG\r\n\x1a\n".to_vec(),
+    );
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .post("users/1/avatar")
+        .multipart(form)
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn post_multipart_file() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.post_multipart_order();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .post("orders/import")
+        .multipart(Multipart::new().file("orders", "tests/fixtures/order4.json"))
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn multipart_content_type_should_have_a_boundary() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    let request = grillon
+        .post("users")
+        .multipart(Multipart::new().text("name", "Isaac"));
+
+    let content_type = request.content_type.unwrap();
+    assert!(content_type
+        .to_str()
+        .unwrap()
+        .starts_with("multipart/form-data; boundary="));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Invalid HTTP request body: Failed to read file located at")]
+async fn multipart_unreadable_file() {
+    let mock_server = HttpMockServer::new();
+    let file = PathBuf::from("tests/fixtures/does_not_exist.png");
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .post("users/1/avatar")
+        .multipart(Multipart::new().file("avatar", file))
+        .assert()
+        .await;
+}
@@ -0,0 +1,111 @@
+use crate::{http_mock_server::stalled_body_server, HttpMockServer};
+use grillon::{
+    dsl::{http::is_success, is},
+    header::{HeaderValue, ACCEPT, USER_AGENT},
+    json, Grillon, Result,
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn request_should_inherit_default_bearer_auth() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.bearer_auth();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .default_bearer_auth("token-123")
+        .get("auth/bearer/endpoint")
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_auth_should_override_default_auth() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.basic_auth();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?.default_bearer_auth("token-123");
+
+    let request = grillon
+        .get("auth/basic/endpoint")
+        .basic_auth("isaac", Some("rayne"));
+
+    assert!(request.bearer_auth.is_none());
+
+    request.assert().await.status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_headers_should_override_default_headers() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_with_default_headers();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .default_headers(vec![("accept", "application/json"), ("x-api-key", "key-1")])?
+        .user_agent("grillon-tests")?
+        .get("defaults")
+        .headers(vec![("x-api-key", "key-2")])
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_agent_should_override_default_headers() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?
+        .user_agent("grillon-tests")?
+        .default_headers(vec![
+            (ACCEPT, HeaderValue::from_static("application/json")),
+            (USER_AGENT, HeaderValue::from_static("curl/8.0")),
+        ])?;
+
+    let request = grillon.get("defaults");
+
+    assert_eq!(
+        request.default_headers.get(USER_AGENT),
+        Some(&HeaderValue::from_static("grillon-tests"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Http request timeout after")]
+async fn request_should_inherit_default_timeout() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_slow_response();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .timeout(Duration::from_millis(50))
+        .get("slow")
+        .assert()
+        .await
+        .status(is(200));
+}
+
+#[tokio::test]
+#[should_panic(expected = "Http request timeout after")]
+async fn request_should_inherit_default_timeout_while_receiving_body() {
+    let url = stalled_body_server();
+
+    Grillon::new(&url)
+        .unwrap()
+        .timeout(Duration::from_millis(100))
+        .get("slow-body")
+        .assert()
+        .await
+        .json_body(is(json!({ "status": "ok" })));
+}
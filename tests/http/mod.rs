@@ -1,2 +1,6 @@
 mod basic_http;
+mod body;
+mod defaults;
 mod https;
+mod query;
+mod timeout;
@@ -0,0 +1,159 @@
+use crate::HttpMockServer;
+use grillon::{
+    dsl::{http::is_success, is},
+    json,
+    report::RequestInfo,
+    Grillon, LogSettings, Result,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct UserSearch {
+    name: &'static str,
+    page: Option<u32>,
+}
+
+#[tokio::test]
+async fn query_params_should_be_encoded() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    let request = grillon
+        .get("users")
+        .query(UserSearch {
+            name: "Isaac Rayne",
+            page: None,
+        })
+        .query_param("tag", "a&b")
+        .query_param("tag", "c");
+
+    assert_eq!(
+        request.url.query(),
+        Some("name=Isaac+Rayne&tag=a%26b&tag=c")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_params_should_be_appended_to_the_path_query() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    let request = grillon
+        .get("users?name=Isaac")
+        .query([("page", 1), ("size", 20)]);
+
+    assert_eq!(request.url.query(), Some("name=Isaac&page=1&size=20"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn empty_query_should_not_change_the_url() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    let request = grillon.get("users").query(Vec::<(&str, &str)>::new());
+
+    assert_eq!(request.url.query(), None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_with_query_params() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.search_users();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .get("users")
+        .query([("name", "Isaac Rayne")])
+        .query([("tag", "a&b"), ("tag", "c")])
+        .assert()
+        .await
+        .status(is_success())
+        .json_path("$[0].id", is(json!(1)));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Invalid HTTP request url: Invalid query parameters")]
+async fn invalid_query_params() {
+    let mock_server = HttpMockServer::new();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("users")
+        .query(json!({ "filter": { "name": "Isaac" } }))
+        .assert()
+        .await;
+}
+
+#[tokio::test]
+async fn invalid_query_params_should_not_change_the_url() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let grillon = Grillon::new(&mock_server.server.url("/"))?;
+
+    let request = grillon
+        .get("users")
+        .query_param("page", 1)
+        .query(json!({ "filter": { "name": "Isaac" } }));
+
+    assert_eq!(request.url.query(), Some("page=1"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_params_should_be_reported_with_each_assertion() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.search_users();
+    let url = mock_server
+        .server
+        .url("/users?name=Isaac+Rayne&tag=a%26b&tag=c");
+
+    let report = Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::JsonOutput)
+        .get("users")
+        .query([("name", "Isaac Rayne"), ("tag", "a&b"), ("tag", "c")])
+        .assert()
+        .await
+        .status(is_success())
+        .json_path("$[0].id", is(json!(2)))
+        .report();
+
+    mock.assert();
+
+    let expected = Some(RequestInfo {
+        method: "GET".to_string(),
+        url,
+    });
+    assert_eq!(report.passed[0].request, expected);
+    assert_eq!(report.failed[0].request, expected);
+    assert_eq!(json!(report.failed[0])["request"]["method"], "GET");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_query_params_should_be_reported_with_the_request() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+
+    let report = Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::StdOutput)
+        .post("users")
+        .query(json!({ "filter": { "name": "Isaac" } }))
+        .assert()
+        .await
+        .report();
+
+    let request = report.unprocessable[0].request.as_ref().unwrap();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.url, mock_server.server.url("/users"));
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use crate::{
+    http_mock_server::{slow_body_server, stalled_body_server},
+    HttpMockServer,
+};
+use grillon::{
+    dsl::{http::is_success, is},
+    json, Grillon, LogSettings, Result,
+};
+use std::time::Duration;
+
+#[tokio::test]
+#[should_panic(expected = "Http request timeout after")]
+async fn request_timeout() {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_slow_response();
+
+    Grillon::new(&mock_server.server.url("/"))
+        .unwrap()
+        .get("slow")
+        .timeout(Duration::from_millis(50))
+        .assert()
+        .await
+        .status(is(200));
+}
+
+#[tokio::test]
+async fn request_timeout_should_override_default_timeout() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    let mock = mock_server.get_slow_response();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .timeout(Duration::from_millis(50))
+        .get("slow")
+        .timeout(Duration::from_secs(5))
+        .assert()
+        .await
+        .status(is_success());
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_timeout_should_not_panic_with_json_output() -> Result<()> {
+    let mock_server = HttpMockServer::new();
+    mock_server.get_slow_response();
+
+    Grillon::new(&mock_server.server.url("/"))?
+        .log_settings(LogSettings::JsonOutput)
+        .get("slow")
+        .timeout(Duration::from_millis(50))
+        .assert()
+        .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Http request timeout after")]
+async fn request_timeout_while_receiving_body() {
+    let url = stalled_body_server();
+
+    Grillon::new(&url)
+        .unwrap()
+        .get("slow-body")
+        .timeout(Duration::from_millis(100))
+        .assert()
+        .await
+        .json_body(is(json!({ "status": "ok" })));
+}
+
+#[tokio::test]
+async fn request_timeout_should_wait_for_body() -> Result<()> {
+    let url = slow_body_server(Duration::from_millis(100));
+
+    Grillon::new(&url)?
+        .get("slow-body")
+        .timeout(Duration::from_secs(5))
+        .assert()
+        .await
+        .status(is_success())
+        .json_body(is(json!({ "status": "ok" })));
+
+    Ok(())
+}
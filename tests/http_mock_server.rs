@@ -6,6 +6,10 @@ use httpmock::{
     Mock, MockServer,
 };
 use serde_json::json;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
 
 pub struct HttpMockServer {
     pub server: httpmock::MockServer,
@@ -24,7 +28,7 @@ impl HttpMockServer {
         }
     }
 
-    pub fn get_valid_user(&self) -> Mock {
+    pub fn get_valid_user(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(GET).path("/users/1");
             then.status(200)
@@ -34,7 +38,76 @@ impl HttpMockServer {
         })
     }
 
-    pub fn post_valid_user(&self) -> Mock {
+    pub fn get_users_page(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET).path("/users");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([
+                    { "id": 1, "name": "Isaac" },
+                    { "id": 2, "name": "Max" },
+                    { "id": 3, "name": "Olivia" }
+                ]));
+        })
+    }
+
+    pub fn get_cart(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET).path("/cart");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({
+                    "items": [
+                        { "name": "notebook", "price": 9.99, "quantity": 2 },
+                        { "name": "pen", "price": 1.5, "quantity": 10 }
+                    ],
+                    "total": 34.98,
+                    "currency": "EUR",
+                    "coupon": null
+                }));
+        })
+    }
+
+    pub fn get_order(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET).path("/orders/ord_1042");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({
+                    "id": "9f1c2d3e-4b5a-4c6d-8e7f-0a1b2c3d4e5f",
+                    "ref": "ord_1042",
+                    "customer": "Isaac",
+                    "total": 34.98,
+                    "createdAt": "2024-05-12T10:42:00Z",
+                    "etag": "W/\"5e-1f2d\""
+                }));
+        })
+    }
+
+    pub fn post_order(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(POST).path("/orders");
+            then.status(201)
+                .header("content-type", "application/json; charset=utf-8")
+                .header("location", "/orders/ord_1042")
+                .header("etag", "W/\"5e-1f2d\"")
+                .json_body(json!({ "ref": "ord_1042" }));
+        })
+    }
+
+    pub fn get_preferences(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET).path("/preferences");
+            then.status(200)
+                .header("vary", "Origin")
+                .header("vary", "Accept-Encoding")
+                .header("set-cookie", "theme=dark; Path=/")
+                .header("set-cookie", "lang=en; Path=/")
+                .json_body(json!({ "theme": "dark", "lang": "en" }));
+        })
+    }
+
+    pub fn post_valid_user(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(POST)
                 .path("/users")
@@ -47,7 +120,7 @@ impl HttpMockServer {
         })
     }
 
-    pub fn put_valid_user(&self) -> Mock {
+    pub fn put_valid_user(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(PUT)
                 .path("/users/1")
@@ -58,14 +131,14 @@ impl HttpMockServer {
         })
     }
 
-    pub fn delete_valid_user(&self) -> Mock {
+    pub fn delete_valid_user(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(DELETE).path("/users/1");
             then.status(204);
         })
     }
 
-    pub fn patch_valid_user(&self) -> Mock {
+    pub fn patch_valid_user(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(PATCH)
                 .header("content-type", "application/json-patch+json")
@@ -79,7 +152,7 @@ impl HttpMockServer {
         })
     }
 
-    pub fn options(&self) -> Mock {
+    pub fn options(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(OPTIONS).path("/");
             then.status(204).header(
@@ -89,14 +162,14 @@ impl HttpMockServer {
         })
     }
 
-    pub fn head(&self) -> Mock {
+    pub fn head(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(HEAD).path("/movies/1");
             then.status(204).header("content-length", "91750400");
         })
     }
 
-    pub fn connect(&self) -> Mock {
+    pub fn connect(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(CONNECT).header(
                 "user-agent",
@@ -106,21 +179,136 @@ impl HttpMockServer {
         })
     }
 
-    pub fn get_empty_response(&self) -> Mock {
+    pub fn search_users(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET)
+                .path("/users")
+                .query_param("name", "Isaac Rayne")
+                .query_param("tag", "a&b")
+                .query_param("tag", "c");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!([{ "id": 1, "name": "Isaac Rayne" }]));
+        })
+    }
+
+    pub fn post_form_user(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(POST)
+                .path("/users")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .x_www_form_urlencoded_tuple("name", "Isaac Rayne")
+                .x_www_form_urlencoded_tuple("city", "Paris");
+
+            then.status(201)
+                .header("content-type", "application/json")
+                .json_body(json!({ "id": 1, "name": "Isaac Rayne" }));
+        })
+    }
+
+    pub fn post_xml_user(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(POST)
+                .path("/users")
+                .header("content-type", "application/xml")
+                .body("<user><name>Isaac</name></user>");
+
+            then.status(201);
+        })
+    }
+
+    pub fn post_text_comment(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(POST)
+                .path("/comments")
+                .header("content-type", "text/plain; charset=utf-8")
+                .body("Nice post!");
+
+            then.status(201);
+        })
+    }
+
+    pub fn put_order_file(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(PUT)
+                .path("/orders/4")
+                .header("content-type", "application/json")
+                .body(include_str!("fixtures/order4.json"));
+
+            then.status(204);
+        })
+    }
+
+    pub fn post_multipart_avatar(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(POST)
+                .path("/users/1/avatar")
+                .header_exists("content-type")
+                .body_contains("Content-Disposition: form-data; name=\"name\"\r\n\r\nIsaac")
+                .body_contains(
+                    "Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"",
+                )
+                .body_contains("Content-Type: image/png");
+
+            then.status(201);
+        })
+    }
+
+    pub fn post_multipart_order(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(POST)
+                .path("/orders/import")
+                .body_contains(
+                    "Content-Disposition: form-data; name=\"orders\"; filename=\"order4.json\"",
+                )
+                .body_contains("Content-Type: application/json");
+
+            then.status(204);
+        })
+    }
+
+    pub fn get_html_page(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET).path("/index.html");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body("<html><body><h1>Hello, Isaac</h1></body></html>");
+        })
+    }
+
+    pub fn get_binary_file(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET).path("/logo.png");
+            then.status(200)
+                .header("content-type", "image/png")
+                .body(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR");
+        })
+    }
+
+    pub fn get_invalid_json(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET).path("/users/invalid");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body("<html><body>Bad gateway</body></html>");
+        })
+    }
+
+    pub fn get_empty_response(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(GET).path("/empty");
             then.status(200);
         })
     }
 
-    pub fn server_error(&self) -> Mock {
+    pub fn server_error(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(GET).path("/server/error");
             then.status(500);
         })
     }
 
-    pub fn basic_auth(&self) -> Mock {
+    pub fn basic_auth(&self) -> Mock<'_> {
         let base64_user_pwd = BASE64_STANDARD.encode(b"isaac:rayne");
         self.server.mock(|when, then| {
             when.method(GET).path("/auth/basic/endpoint").header(
@@ -131,7 +319,7 @@ impl HttpMockServer {
         })
     }
 
-    pub fn bearer_auth(&self) -> Mock {
+    pub fn bearer_auth(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(GET)
                 .path("/auth/bearer/endpoint")
@@ -140,7 +328,34 @@ impl HttpMockServer {
         })
     }
 
-    pub fn session_auth(&self) -> Mock {
+    pub fn get_with_default_headers(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET)
+                .path("/defaults")
+                .header("accept", "application/json")
+                .header("user-agent", "grillon-tests")
+                .matches(|req| {
+                    let api_keys: Vec<_> = req
+                        .headers
+                        .iter()
+                        .flatten()
+                        .filter(|(name, _)| name.eq_ignore_ascii_case("x-api-key"))
+                        .collect();
+
+                    matches!(api_keys.as_slice(), [(_, value)] if value == "key-2")
+                });
+            then.status(200);
+        })
+    }
+
+    pub fn get_slow_response(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(GET).path("/slow");
+            then.status(200).delay(Duration::from_millis(500));
+        })
+    }
+
+    pub fn session_auth(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(POST).path("/auth/session");
             then.status(200)
@@ -148,7 +363,21 @@ impl HttpMockServer {
         })
     }
 
-    pub fn session_based_request(&self) -> Mock {
+    pub fn post_login(&self) -> Mock<'_> {
+        self.server.mock(|when, then| {
+            when.method(POST).path("/login");
+            then.status(204)
+                .header("set-cookie", "theme=light")
+                .header(
+                    "set-cookie",
+                    "session=abc123; Path=/; Domain=.example.com; Max-Age=3600; \
+                    Expires=Wed, 21 Oct 2026 07:28:00 GMT; Secure; HttpOnly; SameSite=Lax",
+                )
+                .header("set-cookie", "theme=dark; SameSite=Strict");
+        })
+    }
+
+    pub fn session_based_request(&self) -> Mock<'_> {
         self.server.mock(|when, then| {
             when.method(GET)
                 .path("/auth/session/endpoint")
@@ -157,3 +386,53 @@ impl HttpMockServer {
         })
     }
 }
+
+/// Starts a server sending the status and headers of its responses right away
+/// and their json body after the given delay. Returns the base url of the
+/// server.
+///
+/// `httpmock` can only delay a whole response, this server is used to delay
+/// the body only.
+pub fn slow_body_server(delay: Duration) -> String {
+    body_server(Some(delay))
+}
+
+/// Starts a server sending the status and headers of its responses right away
+/// but never their body, the connection being held until the client closes
+/// it. Returns the base url of the server.
+pub fn stalled_body_server() -> String {
+    body_server(None)
+}
+
+fn body_server(delay: Option<Duration>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Bound slow body server");
+    let addr = listener.local_addr().expect("Slow body server address");
+
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            thread::spawn(move || {
+                let mut request = [0; 4096];
+                let _ = stream.read(&mut request);
+
+                let body = r#"{"status":"ok"}"#;
+                let head = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(head.as_bytes());
+                let _ = stream.flush();
+
+                match delay {
+                    Some(delay) => {
+                        thread::sleep(delay);
+                        let _ = stream.write_all(body.as_bytes());
+                    }
+                    // Reading returns once the client closes the connection.
+                    None => while matches!(stream.read(&mut request), Ok(read) if read > 0) {},
+                }
+            });
+        }
+    });
+
+    format!("http://{addr}/")
+}